@@ -0,0 +1,29 @@
+use actix_web::error::InternalError;
+use actix_web::http::StatusCode;
+use actix_web::{Error, HttpResponse};
+use frontend::api::error_fragment;
+use log::{error, warn};
+use search::search_error::SearchError;
+use std::fmt::{Debug, Display};
+
+/// Response of a failed search, the details are logged and the user gets a readable message
+pub fn search_error_response(e: &SearchError) -> HttpResponse {
+    error!("{e}");
+
+    let (status, message): (StatusCode, &str) = match e {
+        SearchError::Configuration(_) => (StatusCode::INTERNAL_SERVER_ERROR, "The search engine is misconfigured, the error has been logged."),
+        SearchError::Connection(_) => (StatusCode::SERVICE_UNAVAILABLE, "The database is unreachable, please retry in a moment."),
+        SearchError::Query(_) => (StatusCode::INTERNAL_SERVER_ERROR, "The search failed, the error has been logged."),
+        SearchError::Timeout(_) => (StatusCode::GATEWAY_TIMEOUT, "The database took too long to answer, please retry."),
+    };
+
+    HttpResponse::build(status).body(error_fragment(message))
+}
+
+/// Response of a search form which can't be deserialized
+pub fn form_error<E: Display + Debug>(e: &E) -> Error {
+    warn!("invalid form: {e}");
+    let response: HttpResponse = HttpResponse::BadRequest().body(error_fragment(&format!("Invalid search: {e}")));
+
+    InternalError::from_response(e.to_string(), response).into()
+}
@@ -0,0 +1,43 @@
+use actix_web::dev::Payload;
+use actix_web::web::Bytes;
+use actix_web::{Error, FromRequest, HttpRequest};
+use serde::de::DeserializeOwned;
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+
+use crate::api::error::form_error;
+
+/// Form deserialized by `serde_html_form`, which collects the repeated keys of the
+/// multi-selects into a `Vec` where `web::Form` keeps the last one
+pub struct HtmlForm<T>(pub T);
+
+/// Query string deserialized like [`HtmlForm`]
+pub struct HtmlQuery<T>(pub T);
+
+impl<T: DeserializeOwned + 'static> FromRequest for HtmlForm<T> {
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let body = Bytes::from_request(req, payload);
+
+        Box::pin(async move {
+            serde_html_form::from_bytes(&body.await?)
+                .map(Self)
+                .map_err(|e| form_error(&e))
+        })
+    }
+}
+
+impl<T: DeserializeOwned> FromRequest for HtmlQuery<T> {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(
+            serde_html_form::from_str(req.query_string())
+                .map(Self)
+                .map_err(|e| form_error(&e))
+        )
+    }
+}
@@ -1,2 +1,5 @@
+pub mod error;
+pub mod html_form;
 pub mod powerlifters;
 pub mod root;
+pub mod weight_classes;
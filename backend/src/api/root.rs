@@ -1,9 +1,18 @@
+use actix_web::web::Data;
 use actix_web::{get, HttpResponse, Responder};
-
 use frontend::api::root_page;
+use types::prelude::FederationDto;
+
+use crate::api::error::search_error_response;
+use crate::server::ServerData;
 
 #[get("/")]
-pub async fn root() -> impl Responder {
+pub async fn root(data: Data<ServerData>) -> impl Responder {
+    let federations: Vec<FederationDto> = match data.search_engine.federations().await {
+        Ok(federations) => federations,
+        Err(e) => return search_error_response(&e),
+    };
+
     HttpResponse::Ok()
-        .body(root_page())
+        .body(root_page(federations))
 }
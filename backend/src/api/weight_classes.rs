@@ -0,0 +1,54 @@
+use actix_web::{get, HttpResponse, Responder};
+use frontend::api::weight_class_options;
+use types::filters::WeightClassOptionsDto;
+
+use crate::api::html_form::HtmlQuery;
+
+#[get("/weight_classes")]
+pub async fn weight_classes(query: HtmlQuery<WeightClassOptionsDto>) -> impl Responder {
+    HttpResponse::Ok().body(weight_class_options(&query.0.federation_choice, &query.0.sex_choice))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::body::to_bytes;
+    use actix_web::dev::{Service, ServiceResponse};
+    use actix_web::http::StatusCode;
+    use actix_web::{test, App};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::weight_classes;
+
+    #[rstest]
+    #[case("federation_choice=IPF&sex_choice=Men", StatusCode::OK, &[r#"value="IPF/M/120+""#, r#"label="IPF M""#, r#"label="Pre-2011 M""#], &[r#"label="IPF F""#])]
+    #[case("federation_choice=FFForce&sex_choice=Any", StatusCode::OK, &[r#"label="IPF M""#, r#"label="IPF F""#, r#"value="IPF 2011-2020/F/72""#], &[])]
+    #[case("federation_choice=USAPL&sex_choice=Women", StatusCode::OK, &[r#"value="Any""#, r#"value="Pre-2011/F/67.5""#], &["IPF"])]
+    #[case("federation_choice=USAPL", StatusCode::OK, &[r#"label="Pre-2011 M""#, r#"label="Pre-2011 F""#], &["IPF"])]
+    #[case("federation_choice=USAPL&federation_choice=IPF&sex_choice=Women&sex_choice=Men", StatusCode::OK, &[r#"label="IPF F""#, r#"label="Pre-2011 M""#], &[])]
+    #[case("federation_choice=USAPL&sex_choice=Other", StatusCode::BAD_REQUEST, &["Invalid search"], &[])]
+    #[actix_web::test]
+    async fn test_weight_classes(
+        #[case] query: &str,
+        #[case] expected_status: StatusCode,
+        #[case] expected: &[&str],
+        #[case] unexpected: &[&str],
+    ) {
+        let app = test::init_service(App::new().service(weight_classes)).await;
+
+        let request = test::TestRequest::get()
+            .uri(&format!("/weight_classes?{query}"))
+            .to_request();
+        let response: ServiceResponse = app.call(request).await.unwrap();
+        let status: StatusCode = response.status();
+        let body: String = String::from_utf8(to_bytes(response.into_body()).await.unwrap().to_vec()).unwrap();
+
+        assert_eq!(expected_status, status);
+        for text in expected {
+            assert!(body.contains(text), "{text:?} should be in {body}");
+        }
+        for text in unexpected {
+            assert!(!body.contains(text), "{text:?} shouldn't be in {body}");
+        }
+    }
+}
@@ -1,13 +1,18 @@
 use anyhow::{bail, Result};
 use std::net::IpAddr;
 use std::path::PathBuf;
+use std::time::Duration;
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use repository::{PoolOptions, WriteOnlyRepository};
 
 /// `PowerliftingApi`
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Path to data
     #[arg(long, env = "DATA")]
     pub path: Option<PathBuf>,
@@ -20,13 +25,78 @@ pub struct Args {
     #[arg(long, env = "PORT", requires = "ip")]
     pub port: Option<u16>,
 
-    /// Apply migrations
+    /// Start the server
     #[arg(short, long, default_value = "true")]
     pub start_server: Option<bool>,
 
-    /// Apply migrations
+    /// Import the data into the database
     #[arg(short, long, default_value = "true")]
     pub migrate: Option<bool>,
+
+    /// Serve the data from memory, without a database
+    #[arg(long, env = "IN_MEMORY")]
+    pub in_memory: bool,
+
+    /// Csv file of division aliases (Federation,Division,Canonical) added to the default ones,
+    /// the federation "*" applies to every federation
+    #[arg(long, env = "DIVISION_ALIASES")]
+    pub division_aliases: Option<PathBuf>,
+
+    /// Csv file of the names shared by several lifters (Name,Count), the
+    /// lifter-data/name-disambiguation.csv of opl-data
+    #[arg(long, env = "NAME_DISAMBIGUATION")]
+    pub name_disambiguation: Option<PathBuf>,
+
+    /// Abort the import when a meet can't be parsed
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Number of rows inserted by a statement during the import
+    #[arg(long, env = "IMPORT_BATCH_SIZE", default_value_t = WriteOnlyRepository::DEFAULT_BATCH_SIZE)]
+    pub import_batch_size: usize,
+
+    /// Maximum number of database connections shared by the requests
+    #[arg(long, env = "DATABASE_MAX_CONNECTIONS", default_value_t = PoolOptions::default().max_connections)]
+    pub db_max_connections: u32,
+
+    /// Number of database connections kept open
+    #[arg(long, env = "DATABASE_MIN_CONNECTIONS", default_value_t = PoolOptions::default().min_connections)]
+    pub db_min_connections: u32,
+
+    /// Seconds to wait for a new database connection
+    #[arg(long, env = "DATABASE_CONNECT_TIMEOUT", default_value_t = PoolOptions::default().connect_timeout.as_secs())]
+    pub db_connect_timeout: u64,
+
+    /// Seconds to wait for a free connection of the pool
+    #[arg(long, env = "DATABASE_ACQUIRE_TIMEOUT", default_value_t = PoolOptions::default().acquire_timeout.as_secs())]
+    pub db_acquire_timeout: u64,
+
+    /// Seconds after which an unused connection is closed
+    #[arg(long, env = "DATABASE_IDLE_TIMEOUT", default_value_t = PoolOptions::default().idle_timeout.as_secs())]
+    pub db_idle_timeout: u64,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Manage the database schema without importing the data nor starting the server
+    #[command(subcommand)]
+    Migrate(MigrateCommand),
+}
+
+#[derive(Subcommand)]
+pub enum MigrateCommand {
+    /// Apply the pending migrations
+    Up,
+
+    /// Roll back the last applied migrations
+    Down {
+        /// Number of migrations rolled back
+        #[arg(short, long, default_value_t = 1)]
+        steps: u32,
+    },
+
+    /// List the migrations and whether they are applied
+    Status,
 }
 
 impl Args {
@@ -45,6 +115,20 @@ impl Args {
             }
         }
 
+        if self.db_min_connections > self.db_max_connections {
+            bail!("db-min-connections must not exceed db-max-connections");
+        }
+
         Ok(())
     }
+
+    pub const fn pool_options(&self) -> PoolOptions {
+        PoolOptions {
+            max_connections: self.db_max_connections,
+            min_connections: self.db_min_connections,
+            connect_timeout: Duration::from_secs(self.db_connect_timeout),
+            acquire_timeout: Duration::from_secs(self.db_acquire_timeout),
+            idle_timeout: Duration::from_secs(self.db_idle_timeout),
+        }
+    }
 }
@@ -1,11 +1,15 @@
 use actix_web::dev::Server;
 use anyhow::{bail, Result};
 use clap::Parser;
-use cli::Args;
-use data_parsing::Database;
+use cli::{Args, Command, MigrateCommand};
+use data_parsing::{Database, DivisionAliases, ImportReport, NameDisambiguation};
 use dotenvy::dotenv;
-use log::info;
+use log::{info, warn};
+use repository::{MigrationStatus, ReadOnlyRepository, Repository, WriteOnlyRepository};
+use search::in_memory_backend::InMemoryBackend;
+use search::search_backend::SearchBackend;
 use search::search_engine::SearchEngine;
+use std::sync::Arc;
 
 use crate::server::{start_server, ServerData};
 
@@ -22,27 +26,107 @@ async fn main() -> Result<()> {
         std::env::set_var("RUST_LOG", "debug");
     }
     env_logger::init();
-    dotenv()?;
+    // The in-memory mode needs no database settings
+    dotenv().ok();
 
     let args: Args = match Args::try_parse() {
         Ok(args) => args,
         Err(e) => bail!(e),
     };
+
+    if let Some(Command::Migrate(command)) = args.command {
+        return migrate(command).await;
+    }
+
     args.validate()?;
 
+    if args.in_memory {
+        // The meets are parsed at each start and searched where they are, the database is
+        // neither migrated nor imported into
+        let backend: Arc<InMemoryBackend> = Arc::new(InMemoryBackend::new(&load(&args)?));
+
+        if args.start_server.is_some_and(|start_server| start_server) {
+            serve(&args, SearchEngine::new(backend)).await?;
+        }
+
+        return Ok(());
+    }
+
+    // The schema is brought up to date before anything reads from the database
+    migrate(MigrateCommand::Up).await?;
+
     if args.migrate.is_some_and(|migrate| migrate) {
-        Database::from_directory(args.path.as_ref().unwrap())?.save().await?;
+        load(&args)?.save(args.import_batch_size).await?;
     }
 
     if args.start_server.is_some_and(|start_server| start_server) {
-        let data: ServerData = ServerData {
-            search_engine: SearchEngine,
-        };
-        let server: Server = start_server(args.ip.unwrap(), args.port.unwrap(), data)?;
+        let mut repository: ReadOnlyRepository = Repository::read_only_with_pool(&args.pool_options())?;
+        repository.connect().await?;
+        let repository: Arc<ReadOnlyRepository> = Arc::new(repository);
+
+        serve(&args, SearchEngine::new(Arc::clone(&repository) as Arc<dyn SearchBackend>)).await?;
+
+        if let Some(repository) = Arc::into_inner(repository) {
+            repository.disconnect().await?;
+        }
+    }
+    Ok(())
+}
+
+/// Parses the meets of the data path
+fn load(args: &Args) -> Result<Database> {
+    let aliases: DivisionAliases = match &args.division_aliases {
+        Some(path) => DivisionAliases::from_file(path)?,
+        None => DivisionAliases::default(),
+    };
+    let names: NameDisambiguation = match &args.name_disambiguation {
+        Some(path) => NameDisambiguation::from_file(path)?,
+        None => NameDisambiguation::default(),
+    };
+    let (database, report): (Database, ImportReport) = Database::from_directory(args.path.as_ref().unwrap(), &aliases, &names)?;
 
-        server.await?;
+    if report.is_complete() && report.unknown_divisions.is_empty() && report.ambiguous_names.is_empty() {
+        info!("{report}");
+    } else {
+        warn!("{report}");
+    }
 
-        info!("Server exited cleanly");
+    if args.strict && !report.is_complete() {
+        bail!("import aborted: {} meets skipped in strict mode", report.skipped.len());
     }
+
+    Ok(database)
+}
+
+async fn serve(args: &Args, search_engine: SearchEngine) -> Result<()> {
+    let data: ServerData = ServerData {
+        search_engine,
+    };
+    let server: Server = start_server(args.ip.unwrap(), args.port.unwrap(), data)?;
+
+    server.await?;
+
+    info!("Server exited cleanly");
     Ok(())
 }
+
+async fn migrate(command: MigrateCommand) -> Result<()> {
+    let mut repository: WriteOnlyRepository = Repository::write_only()?;
+    repository.connect().await?;
+
+    match command {
+        MigrateCommand::Up => repository.apply_migrations().await?,
+        MigrateCommand::Down { steps } => repository.rollback_migrations(steps).await?,
+        MigrateCommand::Status => {
+            for (name, status) in repository.migration_status().await? {
+                let status: &str = match status {
+                    MigrationStatus::Applied => "applied",
+                    MigrationStatus::Pending => "pending",
+                };
+                println!("{name}: {status}");
+            }
+        },
+    }
+
+    repository.disconnect().await
+}
@@ -9,6 +9,7 @@ use std::net::IpAddr;
 
 use crate::api::powerlifters::powerlifters;
 use crate::api::root::root;
+use crate::api::weight_classes::weight_classes;
 
 #[derive(Clone, Debug)]
 pub struct ServerData {
@@ -28,6 +29,7 @@ pub fn start_server(ip: IpAddr, port: u16, data: ServerData) -> Result<Server>{
                 .app_data(web::Data::new(data.clone()))
                 .service(root)
                 .service(powerlifters)
+                .service(weight_classes)
                 .default_service(
                     web::route().to(HttpResponse::ImATeapot),
                 )
@@ -1,16 +1,23 @@
 use anyhow::{Result, bail};
-use csv::{Reader, ReaderBuilder};
+use csv::{ErrorKind, Reader, ReaderBuilder, StringRecord};
+use serde::de::DeserializeOwned;
 use itertools::Itertools;
-use log::warn;
+use log::info;
+use rayon::prelude::*;
 use repository::Repository;
+use sha2::{Digest, Sha256};
 use types::prelude::MeetDto;
-use std::fs::File;
+use std::collections::{BTreeSet, HashMap};
+use std::fs::{self, File};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
-use crate::types::Meet;
-use crate::types::MeetData;
+use crate::division_aliases::DivisionAliases;
+use crate::import_report::{ImportReport, SkippedMeet};
+use crate::name_disambiguation::NameDisambiguation;
+use crate::types::{Division, Meet};
+use crate::types::{Federation, MeetData};
 use crate::types::Entry;
 
 const ENTRIES_FILE_NAME: &str = "entries";
@@ -18,61 +25,108 @@ const MEET_FILE_NAME: &str = "meet";
 const CSV_EXTENSION: &str = "csv";
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Database(Vec<Meet>);
+pub struct Database {
+    meets: Vec<Meet>,
+    /// Keys of every meet directory of the tree, whether its meet could be parsed or not
+    directories: BTreeSet<String>,
+    /// Keys of the directories of the tree which couldn't be walked
+    unwalked: BTreeSet<String>,
+}
 
 impl Database {
-    fn from_data_csv(path: &PathBuf) -> Result<MeetData> {
+    fn check_csv_file(path: &Path) -> Result<(), SkippedMeet> {
         if !path.exists() {
-            bail!("path \"{}\" should exist", path.display());
+            return Err(SkippedMeet::file(path, "file should exist"));
         }
 
         if !path.is_file() {
-            bail!("path \"{}\" should be a file", path.display());
+            return Err(SkippedMeet::file(path, "path should be a file"));
         }
 
-        if path.extension().unwrap() != CSV_EXTENSION {
-            bail!("extension of \"{}\" should be \"{}\"", path.display(), CSV_EXTENSION);
+        if path.extension().is_none_or(|extension| extension != CSV_EXTENSION) {
+            return Err(SkippedMeet::file(path, format!("extension should be \"{CSV_EXTENSION}\"")));
         }
 
+        Ok(())
+    }
+
+    /// Csv only knows the column of the errors it raises itself, the column of the errors raised
+    /// by the custom visitors is found by deserializing the row again one column at a time
+    fn deserialize_record<T: DeserializeOwned>(
+        path: &Path,
+        headers: &StringRecord,
+        record: &StringRecord,
+    ) -> Result<T, SkippedMeet> {
+        record.deserialize(Some(headers)).map_err(|e| {
+            let mut skipped: SkippedMeet = SkippedMeet::csv(path, &e, Some(headers));
+
+            if skipped.column.is_none() && matches!(e.kind(), ErrorKind::Deserialize { .. }) {
+                let error: String = skipped.error.clone();
+                skipped.column = (1..=record.len())
+                    .find(|&len| {
+                        let headers: StringRecord = headers.iter().take(len).collect();
+                        let record: StringRecord = record.iter().take(len).collect();
+                        record
+                            .deserialize::<T>(Some(&headers))
+                            .is_err_and(|e| SkippedMeet::csv(path, &e, None).error == error)
+                    })
+                    .and_then(|len| headers.get(len - 1))
+                    .map(str::to_owned);
+            }
+
+            skipped
+        })
+    }
+
+    fn from_data_csv(path: &PathBuf) -> Result<MeetData, SkippedMeet> {
+        Self::check_csv_file(path)?;
+
         let mut reader: Reader<File> = ReaderBuilder::new()
             .quoting(false)
             .from_path(path)
-            .expect("verifications before should be enough");
-
-        let data: MeetData = match reader.deserialize().next() {
-            Some(Ok(data)) => data,
-            Some(Err(e)) => bail!("error with file \"{}\" : {}", path.display(), e),
-            None => bail!("error with file \"{}\" : no rows", path.display()),
+            .map_err(|e| SkippedMeet::csv(path, &e, None))?;
+        let headers: StringRecord = reader
+            .headers()
+            .map_err(|e| SkippedMeet::csv(path, &e, None))?
+            .clone();
+
+        let data: MeetData = match reader.records().next() {
+            Some(Ok(record)) => Self::deserialize_record(path, &headers, &record)?,
+            Some(Err(e)) => return Err(SkippedMeet::csv(path, &e, None)),
+            None => return Err(SkippedMeet::file(path, "file should contain one row")),
         };
 
         Ok(data)
     }
 
-    fn from_entries_csv(path: &PathBuf) -> Result<Vec<Entry>> {
-        if !path.exists() {
-            bail!("path \"{}\" should exist", path.display());
-        }
-
-        if !path.is_file() {
-            bail!("path \"{}\" should be a file", path.display());
-        }
-
-        if path.extension().unwrap() != CSV_EXTENSION {
-            bail!("extension of \"{}\" should be \"{}\"", path.display(), CSV_EXTENSION);
-        }
+    /// Parses the entries of a meet of the federation, their divisions are resolved through
+    /// the aliases of the federation
+    fn from_entries_csv(
+        path: &PathBuf,
+        federation: &Federation,
+        aliases: &DivisionAliases,
+    ) -> Result<Vec<Entry>, SkippedMeet> {
+        Self::check_csv_file(path)?;
 
         let mut reader: Reader<File> = ReaderBuilder::new()
             .quoting(false)
             .from_path(path)
-            .expect("verifications before should be enough");
+            .map_err(|e| SkippedMeet::csv(path, &e, None))?;
+        let headers: StringRecord = reader
+            .headers()
+            .map_err(|e| SkippedMeet::csv(path, &e, None))?
+            .clone();
+
         let mut entries: Vec<Entry> = Vec::with_capacity(50_000);
-        for entry in reader.deserialize() {
-            let entry: Entry = entry?;
+        for record in reader.records() {
+            let record: StringRecord = record.map_err(|e| SkippedMeet::csv(path, &e, None))?;
+            let mut entry: Entry = Self::deserialize_record(path, &headers, &record)?;
+            entry.division = aliases.resolve(federation, &entry.division_raw);
             entries.push(entry);
         }
 
         if entries.is_empty() {
-            bail!("file \"{}\" should contain at least one meet", path.display());
+            return Err(SkippedMeet::file(path, "file should contain at least one entry"));
         }
 
         entries.shrink_to_fit();
@@ -80,7 +134,99 @@ impl Database {
         Ok(entries)
     }
 
-    pub fn from_directory(path: &PathBuf) -> Result<Self> {
+    /// Hash of the content of the files of a meet, used to detect changes between two imports
+    fn hash_files(paths: &[&PathBuf]) -> Result<String> {
+        let mut hasher: Sha256 = Sha256::new();
+
+        for path in paths {
+            hasher.update(fs::read(path)?);
+        }
+
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+
+    /// Key of a meet: its directory relative to the root of the opl-data tree
+    fn meet_key(root: &Path, path: &Path) -> String {
+        let key: String = path
+            .strip_prefix(root)
+            .unwrap_or(path)
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .join("/");
+
+        if key.is_empty() {
+            String::from(".")
+        } else {
+            key
+        }
+    }
+
+    fn from_meet_directory(root: &Path, path: &Path, aliases: &DivisionAliases) -> Result<Meet, SkippedMeet> {
+        let entries_path: PathBuf = path.join(ENTRIES_FILE_NAME).with_extension(CSV_EXTENSION);
+        let data_path: PathBuf = path.join(MEET_FILE_NAME).with_extension(CSV_EXTENSION);
+
+        let data: MeetData = Self::from_data_csv(&data_path)?;
+        let entries: Vec<Entry> = Self::from_entries_csv(&entries_path, &data.federation, aliases)?;
+        let hash: String = Self::hash_files(&[&data_path, &entries_path])
+            .map_err(|e| SkippedMeet::directory(path, e))?;
+
+        Ok(Meet::new(Self::meet_key(root, path), hash, data, entries))
+    }
+
+    /// Lists the directories of a tree
+    fn walk(walk_dir: WalkDir) -> (Vec<PathBuf>, Vec<SkippedMeet>) {
+        let mut directories: Vec<PathBuf> = Vec::new();
+        let mut skipped: Vec<SkippedMeet> = Vec::new();
+
+        for element in walk_dir {
+            match element {
+                Ok(element) if element.file_type().is_dir() => directories.push(element.into_path()),
+                Ok(_) => (),
+                Err(e) => {
+                    let directory: PathBuf = e.path().map(Path::to_owned).unwrap_or_default();
+                    skipped.push(SkippedMeet::directory(&directory, e));
+                },
+            }
+        }
+
+        (directories, skipped)
+    }
+
+    /// Sorted meet directories of the tree, each subdirectory of the root is walked on its own
+    /// thread. Symbolic links are followed
+    fn meet_directories(root: &Path) -> (Vec<PathBuf>, Vec<SkippedMeet>) {
+        let entries_filename: PathBuf = Path::new(ENTRIES_FILE_NAME).with_extension(CSV_EXTENSION);
+        let data_filename: PathBuf = Path::new(MEET_FILE_NAME).with_extension(CSV_EXTENSION);
+
+        let (children, mut skipped): (Vec<PathBuf>, Vec<SkippedMeet>) = Self::walk(WalkDir::new(root).follow_links(true).max_depth(1));
+        let (directories, skipped_children): (Vec<Vec<PathBuf>>, Vec<Vec<SkippedMeet>>) = children
+            .into_par_iter()
+            .map(|child| {
+                if child == root {
+                    (vec![child], Vec::new())
+                } else {
+                    Self::walk(WalkDir::new(child).follow_links(true))
+                }
+            })
+            .unzip();
+
+        let mut directories: Vec<PathBuf> = directories
+            .into_par_iter()
+            .flatten()
+            .filter(|path| path.join(&entries_filename).is_file())
+            .filter(|path| path.join(&data_filename).is_file())
+            .collect();
+        directories.par_sort();
+        skipped.extend(skipped_children.into_iter().flatten());
+        skipped.sort_by(|left, right| left.directory.cmp(&right.directory));
+
+        (directories, skipped)
+    }
+
+    /// Parses every meet directory of the tree, the meets which can't be parsed are listed in
+    /// the report instead of the database, as well as the divisions without alias and the
+    /// shared names which don't tell their lifter apart
+    pub fn from_directory(path: &Path, aliases: &DivisionAliases, names: &NameDisambiguation) -> Result<(Self, ImportReport)> {
         if !path.exists() {
             bail!("path \"{}\" should exist", path.display());
         }
@@ -89,57 +235,109 @@ impl Database {
             bail!("path \"{}\" should be a directory", path.display());
         }
 
-        let entries_filename: PathBuf = Path::new(ENTRIES_FILE_NAME).with_extension(CSV_EXTENSION);
-        let data_filename: PathBuf = Path::new(MEET_FILE_NAME).with_extension(CSV_EXTENSION);
+        let mut report: ImportReport = ImportReport::default();
+        let (directories, skipped): (Vec<PathBuf>, Vec<SkippedMeet>) = Self::meet_directories(path);
+        let unwalked: BTreeSet<String> = skipped
+            .iter()
+            .map(|skipped| Self::meet_key(path, &skipped.directory))
+            .collect();
+        report.skipped.extend(skipped);
 
-        let meets: Vec<Meet> = WalkDir::new(path)
-            .into_iter()
-            .filter_map(Result::ok)
-            .map(|element| element.path().to_owned())
-            .filter(|path| path.is_dir())
-            .filter(|path| path.join(&entries_filename).exists())
-            .filter(|path| path.join(&data_filename).exists())
-            .sorted_by(Ord::cmp)
-            .map(|path| {
-                let entries_path: PathBuf = path.join(&entries_filename);
-                let data_path: PathBuf = path.join(&data_filename);
-
-                let data = match Self::from_data_csv(&data_path) {
-                    Ok(data) => data,
-                    Err(e) => {
-                        warn!("file {} can't be used: {:?}", data_path.display(), e);
-                        bail!(e);
-                    }
-                };
+        let root: &Path = path;
+        let results: Vec<Result<Meet, SkippedMeet>> = directories
+            .par_iter()
+            .map(|directory| Self::from_meet_directory(root, directory, aliases))
+            .collect();
 
-                let entries = match Self::from_entries_csv(&entries_path) {
-                    Ok(entries) => entries,
-                    Err(e) => {
-                        warn!("file {} can't be used: {:?}", entries_path.display(), e);
-                        bail!(e);
-                    }
-                };
+        let mut meets: Vec<Meet> = Vec::with_capacity(results.len());
+        for result in results {
+            match result {
+                Ok(meet) => meets.push(meet),
+                Err(skipped) => report.skipped.push(skipped),
+            }
+        }
 
-                Result::Ok(Meet::new(data, entries))
+        report.imported_meets = meets.len();
+        report.imported_entries = meets.iter().map(|meet| meet.entries.len()).sum();
+        report.unknown_divisions = meets
+            .iter()
+            .flat_map(|meet| {
+                meet.entries
+                    .iter()
+                    .filter(|entry| entry.division == Division::Unknown)
+                    .map(|entry| (meet.data.federation.0.clone(), entry.division_raw.clone()))
             })
-        .filter_map(Result::ok)
+            .collect();
+        report.ambiguous_names = meets
+            .iter()
+            .flat_map(|meet| meet.entries.iter())
+            .filter(|entry| names.is_ambiguous(&entry.name))
+            .map(|entry| entry.name.name.clone())
             .collect();
 
-        Ok(Self(meets))
+        let database: Self = Self {
+            meets,
+            directories: directories.iter().map(|directory| Self::meet_key(root, directory)).collect(),
+            unwalked,
+        };
+
+        Ok((database, report))
+    }
+
+    /// Imported meets whose directory is gone from the tree. The meets which failed to parse
+    /// are kept, as well as the ones under a directory which couldn't be walked
+    fn stale_meets(&self, imported_meets: impl IntoIterator<Item = String>) -> Vec<String> {
+        imported_meets
+            .into_iter()
+            .filter(|path| !self.directories.contains(path))
+            .filter(|path| {
+                !self.unwalked
+                    .iter()
+                    .any(|directory| directory == "." || Path::new(path).starts_with(directory))
+            })
+            .collect()
     }
 
-    pub async fn save(&self) -> Result<()> {
-        let mut write_only_repository = Repository::write_only()?;
+    /// Synchronizes the database with the meets, only the meets whose files changed since the
+    /// last import are written and the meets which are not in the data anymore are deleted.
+    /// The meets are written in transactions of about `batch_size` entries, inserted
+    /// `batch_size` rows per statement
+    pub async fn save(&self, batch_size: usize) -> Result<()> {
+        let mut write_only_repository = Repository::write_only()?.with_batch_size(batch_size);
         write_only_repository.connect().await?;
-        write_only_repository.refresh_migrations().await?;
+        write_only_repository.apply_migrations().await?;
+
+        let imported_meets: HashMap<String, String> = write_only_repository.meet_hashes().await?;
+        let mut inserted: usize = 0;
+        let mut updated: usize = 0;
+        let mut unchanged: usize = 0;
+
+        let changed_meets = self
+            .iter()
+            .filter(|meet| match imported_meets.get(&meet.path) {
+                Some(hash) if *hash == meet.hash => {
+                    unchanged += 1;
+                    false
+                },
+                Some(_) => {
+                    updated += 1;
+                    true
+                },
+                None => {
+                    inserted += 1;
+                    true
+                },
+            })
+            .map(|meet| MeetDto::from(meet.clone()));
+        write_only_repository.upsert_meets(changed_meets).await?;
 
-        for meet in self.iter() {
-            let meet_dto: MeetDto = meet.clone().into();
-            write_only_repository.insert_meet(meet_dto).await?;
-        }
+        let stale_meets: Vec<String> = self.stale_meets(imported_meets.into_keys());
+        let deleted: u64 = write_only_repository.delete_meets(&stale_meets).await?;
 
         write_only_repository.disconnect().await?;
 
+        info!("Import done: {inserted} inserted, {updated} updated, {deleted} deleted, {unchanged} unchanged");
+
         Ok(())
     }
 }
@@ -148,19 +346,29 @@ impl Deref for Database {
     type Target = Vec<Meet>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.meets
     }
 }
 
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
     use pretty_assertions::assert_eq;
     use rstest::rstest;
+    use std::collections::BTreeSet;
+    use std::env;
+    use std::fs;
     use std::path::{Path, PathBuf};
+    use std::process;
     use std::str::FromStr;
+    use types::prelude::CountryDto;
 
-    use crate::types::{Country, Division, Equipment, Federation, Meet, Sex, Username, WeightClass};
+    use crate::division_aliases::DivisionAliases;
+    use crate::name_disambiguation::NameDisambiguation;
+    use crate::import_report::{ImportReport, SkippedMeet};
+    use crate::types::{Age, AgeClass, BirthYearClass, Country, Date, Division, Equipment, Event, Federation, Meet, Place, Sex, Username, WeightClass};
     use crate::types::MeetData;
     use crate::types::Entry;
 
@@ -169,6 +377,14 @@ mod tests {
 
     const TEST_PATH: &str = "test_data/entries/meet_database";
 
+    fn federation() -> Federation {
+        Federation(String::from("FFForce"))
+    }
+
+    fn meet_hash(directory: &Path) -> String {
+        Database::hash_files(&[&directory.join("meet.csv"), &directory.join("entries.csv")]).unwrap()
+    }
+
     #[rstest]
     #[case("test1/entries.csv")]
     #[case("test2/entries.csv")]
@@ -177,7 +393,7 @@ mod tests {
     ) {
         let test_file: PathBuf = Path::new(TEST_PATH).join(entries);
 
-        let result: Result<Vec<Entry>> = Database::from_entries_csv(&test_file);
+        let result: Result<Vec<Entry>, SkippedMeet> = Database::from_entries_csv(&test_file, &federation(), &DivisionAliases::default());
 
         assert!(result.is_ok(), "{}", result.unwrap_err());
     }
@@ -192,7 +408,7 @@ mod tests {
     ) {
         let test_file: PathBuf = Path::new(TEST_PATH).join(file);
 
-        let result: Result<Vec<Entry>> = Database::from_entries_csv(&test_file);
+        let result: Result<Vec<Entry>, SkippedMeet> = Database::from_entries_csv(&test_file, &federation(), &DivisionAliases::default());
 
         assert!(result.is_err());
     }
@@ -202,29 +418,42 @@ mod tests {
         let test_file: PathBuf = Path::new(TEST_PATH).join("test1/entries.csv");
         let expected: Vec<Entry> = vec![
             Entry {
+                place: None,
                 name: Username::from_str("FirstName LastName").unwrap(),
+                cyrillic_name: None,
+                japanese_name: None,
                 division: Division::Masters,
+                division_raw: String::from("Masters"),
                 equipment: Equipment::Raw,
+                event: Event::SBD,
                 sex: Sex::M,
+                country: None,
+                age: None,
+                age_class: None,
+                birth_year_class: None,
                 bodyweight: 104.into(),
                 weight_class: Some(WeightClass::UnderOrEqual(105.into())),
                 squat1: Some(1.into()),
                 squat2: Some(2.into()),
                 squat3: Some(3.into()),
+                squat4: None,
                 best3squat: Some(3.into()),
                 bench1: Some(4.into()),
                 bench2: Some(5.into()),
                 bench3: Some(6.into()),
+                bench4: None,
                 best3bench: Some(6.into()),
                 deadlift1: Some(7.into()),
                 deadlift2: Some(8.into()),
                 deadlift3: Some(9.into()),
+                deadlift4: None,
                 best3deadlift: Some(9.into()),
                 total: Some(18.into()),
+                tested: false,
             },
             ];
 
-        let result: Result<Vec<Entry>> = Database::from_entries_csv(&test_file);
+        let result: Result<Vec<Entry>, SkippedMeet> = Database::from_entries_csv(&test_file, &federation(), &DivisionAliases::default());
 
         assert!(result.is_ok());
         assert_eq!(expected, result.unwrap());
@@ -235,50 +464,76 @@ mod tests {
         let test_file: PathBuf = Path::new(TEST_PATH).join("test2/entries.csv");
         let expected: Vec<Entry> = vec![
             Entry {
+                place: Some(Place::P(1)),
                 name: Username::from_str("Powerlifter 1").unwrap(),
+                cyrillic_name: None,
+                japanese_name: None,
                 division: Division::Masters3,
+                division_raw: String::from("Masters 3"),
                 equipment: Equipment::Raw,
+                event: Event::SBD,
                 sex: Sex::M,
+                country: Some(Country(CountryDto::France)),
+                age: Some(Age(Decimal::new(52, 0))),
+                age_class: Some(AgeClass::Age50To54),
+                birth_year_class: Some(BirthYearClass::Age50To59),
                 bodyweight: 104.into(),
                 weight_class: Some(WeightClass::UnderOrEqual(105.into())),
                 squat1: Some(1.into()),
                 squat2: Some(2.into()),
                 squat3: Some(3.into()),
+                squat4: None,
                 best3squat: Some(3.into()),
                 bench1: Some(4.into()),
                 bench2: Some(5.into()),
                 bench3: Some(6.into()),
+                bench4: Some(6.5.into()),
                 best3bench: Some(6.into()),
                 deadlift1: Some(7.into()),
                 deadlift2: Some(8.into()),
                 deadlift3: Some(9.into()),
+                deadlift4: None,
                 best3deadlift: Some(9.into()),
                 total: Some(18.into()),
+                tested: true,
             },
             Entry {
+                place: Some(Place::DQ),
                 name: Username::from_str("Powerlifter 2").unwrap(),
+                cyrillic_name: None,
+                japanese_name: None,
                 division: Division::Juniors,
+                division_raw: String::from("Juniors"),
                 equipment: Equipment::Raw,
+                event: Event::SBD,
                 sex: Sex::F,
+                country: None,
+                age: Some(Age(Decimal::new(205, 1))),
+                age_class: Some(AgeClass::Age20To23),
+                birth_year_class: Some(BirthYearClass::Age19To23),
                 bodyweight: 80.1.into(),
                 weight_class: Some(WeightClass::UnderOrEqual(84.into())),
                 squat1: Some(10.into()),
                 squat2: Some(11.into()),
                 squat3: Some(12.into()),
+                squat4: None,
                 best3squat: Some(12.into()),
                 bench1: Some(13.into()),
                 bench2: Some(14.into()),
                 bench3: Some(15.into()),
+                bench4: None,
                 best3bench: Some(15.into()),
                 deadlift1: Some(16.into()),
                 deadlift2: Some(17.into()),
                 deadlift3: Some(18.into()),
+                deadlift4: None,
                 best3deadlift: Some(18.into()),
                 total: Some(45.into()),
+                tested: false,
             },
             ];
 
-        let result: Result<Vec<Entry>> = Database::from_entries_csv(&test_file);
+        let result: Result<Vec<Entry>, SkippedMeet> = Database::from_entries_csv(&test_file, &federation(), &DivisionAliases::default());
 
         assert!(result.is_ok());
         assert_eq!(expected, result.unwrap());
@@ -290,7 +545,7 @@ mod tests {
     fn test_from_meet_csv_no_error(#[case] path: &str) {
         let test_file: PathBuf = Path::new(TEST_PATH).join(path);
 
-        let result: Result<MeetData> = Database::from_data_csv(&test_file);
+        let result: Result<MeetData, SkippedMeet> = Database::from_data_csv(&test_file);
 
         assert!(result.is_ok());
     }
@@ -306,7 +561,7 @@ mod tests {
     fn test_from_data_csv_error(#[case] path: &str) {
         let test_file: PathBuf = Path::new(TEST_PATH).join(path);
 
-        let result: Result<MeetData> = Database::from_data_csv(&test_file);
+        let result: Result<MeetData, SkippedMeet> = Database::from_data_csv(&test_file);
 
         assert!(result.is_err());
     }
@@ -315,14 +570,15 @@ mod tests {
     fn test_from_data_1() {
         let test_file: PathBuf = Path::new(TEST_PATH).join("test1/meet.csv");
         let expected: MeetData = MeetData {
-            federation: Federation::FFForce,
-            country: Country::FRANCE,
+            federation: Federation(String::from("FFForce")),
+            country: Some(Country(CountryDto::France)),
             state: "Ile de France".to_string(),
             town: "Paris".to_string(),
             name: "Meet Name".to_string(),
+            date: Some(Date(NaiveDate::from_ymd_opt(2024, 3, 16).unwrap())),
         };
 
-        let result: Result<MeetData> = Database::from_data_csv(&test_file);
+        let result: Result<MeetData, SkippedMeet> = Database::from_data_csv(&test_file);
 
         assert!(result.is_ok());
         assert_eq!(expected, result.unwrap());
@@ -332,249 +588,510 @@ mod tests {
     fn test_from_data_2() {
         let test_file: PathBuf = Path::new(TEST_PATH).join("test2/meet.csv");
         let expected: MeetData = MeetData {
-            federation: Federation::IPF,
-            country: Country::OTHER,
+            federation: Federation(String::from("IPF")),
+            country: Some(Country(CountryDto::Germany)),
             state: String::new(),
             town: String::new(),
             name: "Other Meet".to_string(),
+            date: None,
         };
 
-        let result: Result<MeetData> = Database::from_data_csv(&test_file);
+        let result: Result<MeetData, SkippedMeet> = Database::from_data_csv(&test_file);
 
         assert!(result.is_ok());
         assert_eq!(expected, result.unwrap());
     }
 
+    #[test]
+    fn test_hash_files() {
+        let test_directory: PathBuf = Path::new(TEST_PATH).join("test1/");
+        let meet_path: PathBuf = test_directory.join("meet.csv");
+        let entries_path: PathBuf = test_directory.join("entries.csv");
+
+        let result: Result<String> = Database::hash_files(&[&meet_path, &entries_path]);
+
+        assert!(result.is_ok());
+        let hash: String = result.unwrap();
+        assert_eq!(64, hash.len());
+        assert_eq!(hash, Database::hash_files(&[&meet_path, &entries_path]).unwrap());
+        assert_ne!(hash, Database::hash_files(&[&entries_path, &meet_path]).unwrap());
+        assert_ne!(hash, meet_hash(&Path::new(TEST_PATH).join("test2/")));
+    }
+
+    #[rstest]
+    #[case("test3", "test3", ".")]
+    #[case("test3", "test3/meet1", "meet1")]
+    #[case("opl-data/meet-data", "opl-data/meet-data/ffforce/2401", "ffforce/2401")]
+    fn test_meet_key(
+        #[case] root: &str,
+        #[case] path: &str,
+        #[case] expected: &str,
+    ) {
+        let result: String = Database::meet_key(Path::new(root), Path::new(path));
+
+        assert_eq!(expected, result);
+    }
+
     #[rstest]
     #[case("unknownDirectory/")]
     #[case("invalid/meet.csv")]
     fn test_from_directory_error(#[case] path: &str) {
         let test_file: PathBuf = Path::new(TEST_PATH).join(path);
 
-        let result: Result<Database> = Database::from_directory(&test_file);
+        let result: Result<(Database, ImportReport)> = Database::from_directory(&test_file, &DivisionAliases::default(), &NameDisambiguation::default());
 
         assert!(result.is_err());
     }
 
     #[rstest]
-    #[case("invalid/meet1/")]
-    #[case("invalid/meet2/")]
-    #[case("invalid/meet3/")]
-    #[case("invalid/meet4/")]
-    fn test_from_directory_missing_file(#[case] path: &str) {
+    #[case("invalid/meet1/", 0)]
+    #[case("invalid/meet2/", 0)]
+    #[case("invalid/meet3/", 1)]
+    #[case("invalid/meet4/", 1)]
+    #[case("invalid/meet5/", 1)]
+    fn test_from_directory_skipped(
+        #[case] path: &str,
+        #[case] skipped: usize,
+    ) {
         let test_file: PathBuf = Path::new(TEST_PATH).join(path);
-        let expected: Database = Database(Vec::new());
+        let expected: Vec<Meet> = Vec::new();
 
-        let result: Result<Database> = Database::from_directory(&test_file);
+        let result: Result<(Database, ImportReport)> = Database::from_directory(&test_file, &DivisionAliases::default(), &NameDisambiguation::default());
 
         assert!(result.is_ok());
-        assert_eq!(expected, result.unwrap());
+        let (database, report): (Database, ImportReport) = result.unwrap();
+        assert_eq!(expected, *database);
+        assert_eq!(0, report.imported_meets);
+        assert_eq!(0, report.imported_entries);
+        assert_eq!(skipped, report.skipped.len());
+    }
+
+    #[test]
+    fn test_from_directory_skipped_row() {
+        let test_directory: PathBuf = Path::new(TEST_PATH).join("invalid/");
+        let expected: Vec<SkippedMeet> = vec![
+            SkippedMeet::file(&test_directory.join("meet3/entries.csv"), "file should contain at least one entry"),
+            SkippedMeet::file(&test_directory.join("meet4/meet.csv"), "file should contain one row"),
+            SkippedMeet {
+                row: Some(3),
+                column: Some(String::from("BodyweightKg")),
+                ..SkippedMeet::file(&test_directory.join("meet5/entries.csv"), "invalid float literal")
+            },
+        ];
+
+        let result: Result<(Database, ImportReport)> = Database::from_directory(&test_directory, &DivisionAliases::default(), &NameDisambiguation::default());
+
+        assert!(result.is_ok());
+        let (_, report): (Database, ImportReport) = result.unwrap();
+        assert_eq!(expected, report.skipped);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_from_directory_symlinks() {
+        let test_directory: PathBuf = std::env::temp_dir().join("meet_database_symlinks");
+        let _ = std::fs::remove_dir_all(&test_directory);
+        std::fs::create_dir_all(test_directory.join("ffforce")).unwrap();
+        let meet: PathBuf = std::fs::canonicalize(Path::new(TEST_PATH).join("test1")).unwrap();
+        std::os::unix::fs::symlink(&meet, test_directory.join("ffforce/2401")).unwrap();
+        std::os::unix::fs::symlink(&meet, test_directory.join("ipf")).unwrap();
+
+        let result: Result<(Database, ImportReport)> = Database::from_directory(&test_directory, &DivisionAliases::default(), &NameDisambiguation::default());
+
+        assert!(result.is_ok());
+        let (database, report): (Database, ImportReport) = result.unwrap();
+        let paths: Vec<&str> = database.iter().map(|meet| meet.path.as_str()).collect();
+        assert!(report.is_complete(), "{report}");
+        assert_eq!(vec!["ffforce/2401", "ipf"], paths);
+    }
+
+    #[test]
+    fn test_stale_meets() {
+        let test_directory: PathBuf = Path::new(TEST_PATH).join("invalid/");
+        let imported_meets: Vec<String> = ["meet1", "meet3", "meet4", "meet5", "gone"].map(String::from).to_vec();
+        let expected: Vec<String> = vec![String::from("meet1"), String::from("gone")];
+
+        let (database, report): (Database, ImportReport) = Database::from_directory(&test_directory, &DivisionAliases::default(), &NameDisambiguation::default()).unwrap();
+        let result: Vec<String> = database.stale_meets(imported_meets);
+
+        assert_eq!(3, report.skipped.len());
+        assert_eq!(expected, result);
+    }
+
+    #[rstest]
+    #[case("usapl", &["ipf/2401", "gone"])]
+    #[case(".", &[])]
+    fn test_stale_meets_unwalked(
+        #[case] unwalked: &str,
+        #[case] expected: &[&str],
+    ) {
+        let database: Database = Database {
+            meets: Vec::new(),
+            directories: BTreeSet::from([String::from("ipf/2402")]),
+            unwalked: BTreeSet::from([String::from(unwalked)]),
+        };
+        let imported_meets: Vec<String> = ["ipf/2401", "ipf/2402", "usapl/2401", "gone"].map(String::from).to_vec();
+
+        let result: Vec<String> = database.stale_meets(imported_meets);
+
+        assert_eq!(expected, result);
     }
 
     #[test]
     fn test_from_directory_1() {
         let test_directory: PathBuf = Path::new(TEST_PATH).join("test1/");
-        let expected: Database = Database(vec![
+        let expected: Vec<Meet> = vec![
             Meet {
+                path: String::from("."),
+                hash: meet_hash(&test_directory),
                 data: MeetData {
-                    federation: Federation::FFForce,
-                    country: Country::FRANCE,
+                    federation: Federation(String::from("FFForce")),
+                    country: Some(Country(CountryDto::France)),
                     state: "Ile de France".to_string(),
                     town: "Paris".to_string(),
                     name: "Meet Name".to_string(),
+                    date: Some(Date(NaiveDate::from_ymd_opt(2024, 3, 16).unwrap())),
                 },
                 entries: vec![
                     Entry {
+                        place: None,
                         name: Username::from_str("FirstName LastName").unwrap(),
+                        cyrillic_name: None,
+                        japanese_name: None,
                         division: Division::Masters,
+                        division_raw: String::from("Masters"),
                         equipment: Equipment::Raw,
+                        event: Event::SBD,
                         sex: Sex::M,
+                        country: None,
+                        age: None,
+                        age_class: None,
+                        birth_year_class: None,
                         bodyweight: 104.into(),
                         weight_class: Some(WeightClass::UnderOrEqual(105.into())),
                         squat1: Some(1.into()),
                         squat2: Some(2.into()),
                         squat3: Some(3.into()),
+                        squat4: None,
                         best3squat: Some(3.into()),
                         bench1: Some(4.into()),
                         bench2: Some(5.into()),
                         bench3: Some(6.into()),
+                        bench4: None,
                         best3bench: Some(6.into()),
                         deadlift1: Some(7.into()),
                         deadlift2: Some(8.into()),
                         deadlift3: Some(9.into()),
+                        deadlift4: None,
                         best3deadlift: Some(9.into()),
                         total: Some(18.into()),
+                        tested: false,
                     }
                 ],
             }
-        ]);
+        ];
 
-        let result: Result<Database> = Database::from_directory(&test_directory);
+        let result: Result<(Database, ImportReport)> = Database::from_directory(&test_directory, &DivisionAliases::default(), &NameDisambiguation::default());
 
         assert!(result.is_ok());
-        assert_eq!(expected, result.unwrap());
+        let (database, report): (Database, ImportReport) = result.unwrap();
+        assert_eq!(expected, *database);
+        assert!(report.is_complete());
     }
 
     #[test]
     fn test_from_directory_2() {
         let test_directory: PathBuf = Path::new(TEST_PATH).join("test2/");
-        let expected: Database = Database(vec![
+        let expected: Vec<Meet> = vec![
             Meet {
+                path: String::from("."),
+                hash: meet_hash(&test_directory),
                 data: MeetData {
-                    federation: Federation::IPF,
-                    country: Country::OTHER,
+                    federation: Federation(String::from("IPF")),
+                    country: Some(Country(CountryDto::Germany)),
                     state: String::new(),
                     town: String::new(),
                     name: "Other Meet".to_string(),
+                    date: None,
                 },
                 entries: vec![
                     Entry {
+                        place: Some(Place::P(1)),
                         name: Username::from_str("Powerlifter 1").unwrap(),
+                        cyrillic_name: None,
+                        japanese_name: None,
                         division: Division::Masters3,
+                        division_raw: String::from("Masters 3"),
                         equipment: Equipment::Raw,
+                        event: Event::SBD,
                         sex: Sex::M,
+                        country: Some(Country(CountryDto::France)),
+                        age: Some(Age(Decimal::new(52, 0))),
+                        age_class: Some(AgeClass::Age50To54),
+                        birth_year_class: Some(BirthYearClass::Age50To59),
                         bodyweight: 104.into(),
                         weight_class: Some(WeightClass::UnderOrEqual(105.into())),
                         squat1: Some(1.into()),
                         squat2: Some(2.into()),
                         squat3: Some(3.into()),
+                        squat4: None,
                         best3squat: Some(3.into()),
                         bench1: Some(4.into()),
                         bench2: Some(5.into()),
                         bench3: Some(6.into()),
+                        bench4: Some(6.5.into()),
                         best3bench: Some(6.into()),
                         deadlift1: Some(7.into()),
                         deadlift2: Some(8.into()),
                         deadlift3: Some(9.into()),
+                        deadlift4: None,
                         best3deadlift: Some(9.into()),
                         total: Some(18.into()),
+                        tested: true,
                     },
                     Entry {
+                        place: Some(Place::DQ),
                         name: Username::from_str("Powerlifter 2").unwrap(),
+                        cyrillic_name: None,
+                        japanese_name: None,
                         division: Division::Juniors,
+                        division_raw: String::from("Juniors"),
                         equipment: Equipment::Raw,
+                        event: Event::SBD,
                         sex: Sex::F,
+                        country: None,
+                        age: Some(Age(Decimal::new(205, 1))),
+                        age_class: Some(AgeClass::Age20To23),
+                        birth_year_class: Some(BirthYearClass::Age19To23),
                         bodyweight: 80.1.into(),
                         weight_class: Some(WeightClass::UnderOrEqual(84.into())),
                         squat1: Some(10.into()),
                         squat2: Some(11.into()),
                         squat3: Some(12.into()),
+                        squat4: None,
                         best3squat: Some(12.into()),
                         bench1: Some(13.into()),
                         bench2: Some(14.into()),
                         bench3: Some(15.into()),
+                        bench4: None,
                         best3bench: Some(15.into()),
                         deadlift1: Some(16.into()),
                         deadlift2: Some(17.into()),
                         deadlift3: Some(18.into()),
+                        deadlift4: None,
                         best3deadlift: Some(18.into()),
                         total: Some(45.into()),
+                        tested: false,
                     }
                 ],
             }
-        ]);
+        ];
 
-        let result: Result<Database> = Database::from_directory(&test_directory);
+        let result: Result<(Database, ImportReport)> = Database::from_directory(&test_directory, &DivisionAliases::default(), &NameDisambiguation::default());
 
         assert!(result.is_ok());
-        assert_eq!(expected, result.unwrap());
+        let (database, report): (Database, ImportReport) = result.unwrap();
+        assert_eq!(expected, *database);
+        assert!(report.is_complete());
 
     }
 
     #[test]
     fn test_from_directory_3() {
         let test_file: PathBuf = Path::new(TEST_PATH).join("test3/");
-        let expected: Database = Database(vec![
+        let expected: Vec<Meet> = vec![
             Meet {
+                path: String::from("meet1"),
+                hash: meet_hash(&test_file.join("meet1")),
                 data: MeetData {
-                    federation: Federation::FFForce,
-                    country: Country::FRANCE,
+                    federation: Federation(String::from("FFForce")),
+                    country: Some(Country(CountryDto::France)),
                     state: "Ile de France".to_string(),
                     town: "Paris".to_string(),
                     name: "Meet Name".to_string(),
+                    date: Some(Date(NaiveDate::from_ymd_opt(2024, 3, 16).unwrap())),
                 },
                 entries: vec![
                     Entry {
+                        place: None,
                         name: Username::from_str("FirstName LastName").unwrap(),
+                        cyrillic_name: None,
+                        japanese_name: None,
                         division: Division::Masters,
+                        division_raw: String::from("Masters"),
                         equipment: Equipment::Raw,
+                        event: Event::SBD,
                         sex: Sex::M,
+                        country: None,
+                        age: None,
+                        age_class: None,
+                        birth_year_class: None,
                         bodyweight: 104.into(),
                         weight_class: Some(WeightClass::UnderOrEqual(105.into())),
                         squat1: Some(1.into()),
                         squat2: Some(2.into()),
                         squat3: Some(3.into()),
+                        squat4: None,
                         best3squat: Some(3.into()),
                         bench1: Some(4.into()),
                         bench2: Some(5.into()),
                         bench3: Some(6.into()),
+                        bench4: None,
                         best3bench: Some(6.into()),
                         deadlift1: Some(7.into()),
                         deadlift2: Some(8.into()),
                         deadlift3: Some(9.into()),
+                        deadlift4: None,
                         best3deadlift: Some(9.into()),
                         total: Some(18.into()),
+                        tested: false,
                     }
                 ],
             },
             Meet {
+                path: String::from("meet2"),
+                hash: meet_hash(&test_file.join("meet2")),
                 data: MeetData {
-                    federation: Federation::IPF,
-                    country: Country::OTHER,
+                    federation: Federation(String::from("IPF")),
+                    country: Some(Country(CountryDto::Germany)),
                     state: String::new(),
                     town: String::new(),
                     name: "Other Meet".to_string(),
+                    date: None,
                 },
                 entries: vec![
                     Entry {
+                        place: Some(Place::P(1)),
                         name: Username::from_str("Powerlifter 1").unwrap(),
+                        cyrillic_name: None,
+                        japanese_name: None,
                         division: Division::Masters3,
+                        division_raw: String::from("Masters 3"),
                         equipment: Equipment::Raw,
+                        event: Event::SBD,
                         sex: Sex::M,
+                        country: Some(Country(CountryDto::France)),
+                        age: Some(Age(Decimal::new(52, 0))),
+                        age_class: Some(AgeClass::Age50To54),
+                        birth_year_class: Some(BirthYearClass::Age50To59),
                         bodyweight: 104.into(),
                         weight_class: Some(WeightClass::UnderOrEqual(105.into())),
                         squat1: Some(1.into()),
                         squat2: Some(2.into()),
                         squat3: Some(3.into()),
+                        squat4: None,
                         best3squat: Some(3.into()),
                         bench1: Some(4.into()),
                         bench2: Some(5.into()),
                         bench3: Some(6.into()),
+                        bench4: Some(6.5.into()),
                         best3bench: Some(6.into()),
                         deadlift1: Some(7.into()),
                         deadlift2: Some(8.into()),
                         deadlift3: Some(9.into()),
+                        deadlift4: None,
                         best3deadlift: Some(9.into()),
                         total: Some(18.into()),
+                        tested: true,
                     },
                     Entry {
+                        place: Some(Place::DQ),
                         name: Username::from_str("Powerlifter 2").unwrap(),
+                        cyrillic_name: None,
+                        japanese_name: None,
                         division: Division::Juniors,
+                        division_raw: String::from("Juniors"),
                         equipment: Equipment::Raw,
+                        event: Event::SBD,
                         sex: Sex::F,
+                        country: None,
+                        age: Some(Age(Decimal::new(205, 1))),
+                        age_class: Some(AgeClass::Age20To23),
+                        birth_year_class: Some(BirthYearClass::Age19To23),
                         bodyweight: 80.1.into(),
                         weight_class: Some(WeightClass::UnderOrEqual(84.into())),
                         squat1: Some(10.into()),
                         squat2: Some(11.into()),
                         squat3: Some(12.into()),
+                        squat4: None,
                         best3squat: Some(12.into()),
                         bench1: Some(13.into()),
                         bench2: Some(14.into()),
                         bench3: Some(15.into()),
+                        bench4: None,
                         best3bench: Some(15.into()),
                         deadlift1: Some(16.into()),
                         deadlift2: Some(17.into()),
                         deadlift3: Some(18.into()),
+                        deadlift4: None,
                         best3deadlift: Some(18.into()),
                         total: Some(45.into()),
+                        tested: false,
                     }
                 ],
             }
-        ]);
+        ];
 
-        let result: Result<Database> = Database::from_directory(&test_file);
+        let result: Result<(Database, ImportReport)> = Database::from_directory(&test_file, &DivisionAliases::default(), &NameDisambiguation::default());
 
         assert!(result.is_ok());
-        assert_eq!(expected, result.unwrap());
+        let (database, report): (Database, ImportReport) = result.unwrap();
+        assert_eq!(expected, *database);
+        assert_eq!(3, report.imported_entries);
+        assert!(report.is_complete());
+    }
+
+    #[rstest]
+    #[case(None, Division::Unknown, 1)]
+    #[case(Some("Federation,Division,Canonical\nUSAPL,Teen 16-17,Sub-Juniors\n"), Division::SubJuniors, 0)]
+    fn test_from_directory_unknown_division(
+        #[case] aliases: Option<&str>,
+        #[case] expected: Division,
+        #[case] unknown: usize,
+    ) {
+        let test_directory: PathBuf = Path::new(TEST_PATH).join("test4/");
+        let mut division_aliases: DivisionAliases = DivisionAliases::default();
+        if let Some(aliases) = aliases {
+            let path: PathBuf = std::env::temp_dir().join(format!("division_aliases_{unknown}.csv"));
+            std::fs::write(&path, aliases).unwrap();
+            division_aliases = DivisionAliases::from_file(&path).unwrap();
+        }
+
+        let result: Result<(Database, ImportReport)> = Database::from_directory(&test_directory, &division_aliases, &NameDisambiguation::default());
+
+        assert!(result.is_ok());
+        let (database, report): (Database, ImportReport) = result.unwrap();
+        let entries: &Vec<Entry> = &database[0].entries;
+        assert!(report.is_complete());
+        assert_eq!(expected, entries[0].division);
+        assert_eq!("Teen 16-17", entries[0].division_raw);
+        assert_eq!(Division::Open, entries[1].division);
+        assert_eq!(Some(String::from("Иван Петров")), entries[5].cyrillic_name);
+        assert_eq!(Some(String::from("山田 太郎")), entries[6].japanese_name);
+        assert_eq!(None, entries[6].cyrillic_name);
+        assert_eq!(unknown, report.unknown_divisions.len());
+    }
+
+    #[rstest]
+    #[case("Name,Count\nThomas Martin,2\n", &[])]
+    #[case("Name,Count\nThomas Martin,1\nOpen Lifter,2\n", &["Open Lifter", "Thomas Martin #2"])]
+    fn test_from_directory_ambiguous_names(
+        #[case] content: &str,
+        #[case] expected: &[&str],
+    ) {
+        let test_directory: PathBuf = Path::new(TEST_PATH).join("test4/");
+        let path: PathBuf = env::temp_dir().join(format!("name_disambiguation_{}_{}.csv", process::id(), expected.len()));
+        fs::write(&path, content).unwrap();
+        let names: Result<NameDisambiguation> = NameDisambiguation::from_file(&path);
+        fs::remove_file(&path).unwrap();
+        let names: NameDisambiguation = names.unwrap();
+
+        let result: Result<(Database, ImportReport)> = Database::from_directory(&test_directory, &DivisionAliases::default(), &names);
+
+        assert!(result.is_ok());
+        let (database, report): (Database, ImportReport) = result.unwrap();
+        let ambiguous_names: Vec<&str> = report.ambiguous_names.iter().map(String::as_str).collect();
+        assert_eq!(1, database.len());
+        assert_eq!(expected, ambiguous_names);
     }
 }
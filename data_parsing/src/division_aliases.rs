@@ -0,0 +1,142 @@
+use anyhow::{Context, Result};
+use csv::ReaderBuilder;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::types::{Division, Federation};
+
+/// Aliases shipped with the crate, the aliases of a custom file are added on top of them
+const DEFAULT_ALIASES: &str = include_str!("../division_aliases.csv");
+
+/// Federation of the aliases which apply to every federation
+const ANY_FEDERATION: &str = "*";
+
+#[derive(Debug, Deserialize)]
+struct DivisionAlias {
+    #[serde(rename(deserialize = "Federation"))]
+    federation: String,
+
+    #[serde(rename(deserialize = "Division"))]
+    division: String,
+
+    #[serde(rename(deserialize = "Canonical"))]
+    canonical: String,
+}
+
+/// Maps the raw divisions of the entries of a federation to the canonical divisions
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DivisionAliases(HashMap<(String, String), Division>);
+
+impl DivisionAliases {
+    /// Default aliases extended with the aliases of the file, the aliases of the file win
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let file: File = File::open(path).with_context(|| format!("cannot open division aliases \"{}\"", path.display()))?;
+
+        let mut aliases: Self = Self::default();
+        aliases
+            .extend(file)
+            .with_context(|| format!("invalid division aliases \"{}\"", path.display()))?;
+
+        Ok(aliases)
+    }
+
+    fn extend(&mut self, reader: impl Read) -> Result<()> {
+        for alias in ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader).deserialize() {
+            let alias: DivisionAlias = alias?;
+            let canonical: Division = Division::from_str(&alias.canonical)
+                .with_context(|| format!("unknown canonical division \"{}\"", alias.canonical))?;
+            self.0.insert((alias.federation, alias.division), canonical);
+        }
+
+        Ok(())
+    }
+
+    /// Alias of the federation, then alias of every federation, then canonical name, otherwise
+    /// the division is unknown
+    pub fn resolve(&self, federation: &Federation, division: &str) -> Division {
+        let division: &str = division.trim();
+
+        self.0
+            .get(&(federation.0.clone(), division.to_owned()))
+            .or_else(|| self.0.get(&(ANY_FEDERATION.to_owned(), division.to_owned())))
+            .copied()
+            .or_else(|| Division::from_str(division).ok())
+            .unwrap_or(Division::Unknown)
+    }
+}
+
+impl Default for DivisionAliases {
+    fn default() -> Self {
+        let mut aliases: Self = Self(HashMap::new());
+        aliases
+            .extend(DEFAULT_ALIASES.as_bytes())
+            .expect("default division aliases should be valid");
+
+        aliases
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::types::{Division, Federation};
+
+    use super::DivisionAliases;
+
+    fn aliases(content: &str) -> DivisionAliases {
+        let mut aliases: DivisionAliases = DivisionAliases::default();
+        aliases.extend(content.as_bytes()).unwrap();
+        aliases
+    }
+
+    #[rstest]
+    #[case("FFForce", "Hors Match", Division::G)]
+    #[case("FFForce", "Prime Time", Division::G)]
+    #[case("FFForce", "Subjunior/Junior", Division::SubJuniors)]
+    #[case("FFForce", "Jeunes", Division::Juniors)]
+    #[case("IPF", "Senior/Master", Division::Seniors)]
+    #[case("IPF", "Masters 2", Division::Masters2)]
+    #[case("IPF", " Open ", Division::Open)]
+    #[case("IPF", "Teen 16-17", Division::Unknown)]
+    #[case("IPF", "", Division::Unknown)]
+    fn test_resolve_default(
+        #[case] federation: &str,
+        #[case] division: &str,
+        #[case] expected: Division,
+    ) {
+        let federation: Federation = Federation(String::from(federation));
+
+        assert_eq!(expected, DivisionAliases::default().resolve(&federation, division));
+    }
+
+    #[rstest]
+    #[case("USAPL", "Teen 16-17", Division::SubJuniors)]
+    #[case("USAPL", "Jeunes", Division::Cadet)]
+    #[case("FFForce", "Jeunes", Division::Juniors)]
+    #[case("FFForce", "Teen 16-17", Division::Unknown)]
+    fn test_resolve_per_federation(
+        #[case] federation: &str,
+        #[case] division: &str,
+        #[case] expected: Division,
+    ) {
+        let aliases: DivisionAliases = aliases("Federation,Division,Canonical\nUSAPL,Teen 16-17,Sub-Juniors\nUSAPL,Jeunes,Cadet\n");
+        let federation: Federation = Federation(String::from(federation));
+
+        assert_eq!(expected, aliases.resolve(&federation, division));
+    }
+
+    #[rstest]
+    #[case("Federation,Division,Canonical\nUSAPL,Teen,Teenagers\n")]
+    #[case("Federation,Division\nUSAPL,Teen\n")]
+    fn test_extend_error(#[case] content: &str) {
+        let mut aliases: DivisionAliases = DivisionAliases::default();
+
+        assert!(aliases.extend(content.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use csv::{Error, ErrorKind, Position, StringRecord};
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+/// Reason why a meet directory could not be imported
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SkippedMeet {
+    pub directory: PathBuf,
+    pub file: Option<PathBuf>,
+    pub row: Option<u64>,
+    pub column: Option<String>,
+    pub error: String,
+}
+
+impl SkippedMeet {
+    pub fn directory(directory: &Path, error: impl Display) -> Self {
+        Self {
+            directory: directory.to_owned(),
+            file: None,
+            row: None,
+            column: None,
+            error: error.to_string(),
+        }
+    }
+
+    pub fn file(file: &Path, error: impl Display) -> Self {
+        Self {
+            directory: file.parent().unwrap_or(file).to_owned(),
+            file: Some(file.to_owned()),
+            row: None,
+            column: None,
+            error: error.to_string(),
+        }
+    }
+
+    /// Locates a csv error in the file, the column is only known for deserialization errors
+    pub fn csv(file: &Path, error: &Error, headers: Option<&StringRecord>) -> Self {
+        let row: Option<u64> = error.position().map(Position::line);
+
+        let (column, error): (Option<String>, String) = match error.kind() {
+            ErrorKind::Deserialize { err, .. } => {
+                let column: Option<String> = err
+                    .field()
+                    .and_then(|field| headers.and_then(|headers| headers.get(field as usize)))
+                    .map(str::to_owned);
+                (column, err.kind().to_string())
+            },
+            _ => (None, error.to_string()),
+        };
+
+        Self {
+            row,
+            column,
+            error,
+            ..Self::file(file, "")
+        }
+    }
+}
+
+impl Display for SkippedMeet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.as_ref().unwrap_or(&self.directory).display())?;
+
+        if let Some(row) = self.row {
+            write!(f, ", row {row}")?;
+        }
+
+        if let Some(column) = &self.column {
+            write!(f, ", column \"{column}\"")?;
+        }
+
+        write!(f, ": {}", self.error)
+    }
+}
+
+/// Outcome of the parsing of an opl-data tree
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ImportReport {
+    pub imported_meets: usize,
+    pub imported_entries: usize,
+    pub skipped: Vec<SkippedMeet>,
+    /// Federation and raw text of the divisions imported as unknown
+    pub unknown_divisions: BTreeSet<(String, String)>,
+    /// Names shared by several lifters used without a valid number, their entries can't be
+    /// told apart
+    pub ambiguous_names: BTreeSet<String>,
+}
+
+impl ImportReport {
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty()
+    }
+}
+
+impl Display for ImportReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Parsing done: {} meets ({} entries) parsed, {} skipped",
+            self.imported_meets,
+            self.imported_entries,
+            self.skipped.len(),
+        )?;
+
+        for skipped in &self.skipped {
+            write!(f, "\n  skipped {skipped}")?;
+        }
+
+        for (federation, division) in &self.unknown_divisions {
+            write!(f, "\n  unknown division \"{division}\" ({federation})")?;
+        }
+
+        for name in &self.ambiguous_names {
+            write!(f, "\n  ambiguous name \"{name}\"")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use std::collections::BTreeSet;
+    use std::path::PathBuf;
+
+    use super::{ImportReport, SkippedMeet};
+
+    #[rstest]
+    #[case(None, None, "meet/entries.csv: no rows")]
+    #[case(Some(3), None, "meet/entries.csv, row 3: no rows")]
+    #[case(Some(3), Some("BodyweightKg"), "meet/entries.csv, row 3, column \"BodyweightKg\": no rows")]
+    fn test_skipped_meet_display(
+        #[case] row: Option<u64>,
+        #[case] column: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        let skipped: SkippedMeet = SkippedMeet {
+            row,
+            column: column.map(str::to_owned),
+            ..SkippedMeet::file(&PathBuf::from("meet/entries.csv"), "no rows")
+        };
+
+        assert_eq!(PathBuf::from("meet"), skipped.directory);
+        assert_eq!(expected, skipped.to_string());
+    }
+
+    #[test]
+    fn test_import_report_display() {
+        let report: ImportReport = ImportReport {
+            imported_meets: 2,
+            imported_entries: 3,
+            skipped: vec![SkippedMeet::directory(&PathBuf::from("meet"), "permission denied")],
+            unknown_divisions: BTreeSet::from([(String::from("USAPL"), String::from("Teen 16-17"))]),
+            ambiguous_names: BTreeSet::from([String::from("Thomas Martin")]),
+        };
+
+        assert!(!report.is_complete());
+        assert_eq!(
+            "Parsing done: 2 meets (3 entries) parsed, 1 skipped\n  skipped meet: permission denied\n  unknown division \"Teen 16-17\" (USAPL)\n  ambiguous name \"Thomas Martin\"",
+            report.to_string(),
+        );
+    }
+}
@@ -1,4 +1,10 @@
 mod database;
+mod division_aliases;
+mod import_report;
+mod name_disambiguation;
 mod types;
 
 pub use database::Database;
+pub use division_aliases::DivisionAliases;
+pub use import_report::{ImportReport, SkippedMeet};
+pub use name_disambiguation::NameDisambiguation;
@@ -0,0 +1,112 @@
+use anyhow::{Context, Result};
+use csv::ReaderBuilder;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::types::Username;
+
+#[derive(Debug, Deserialize)]
+struct SharedName {
+    #[serde(rename(deserialize = "Name"))]
+    name: String,
+
+    #[serde(rename(deserialize = "Count"))]
+    count: u16,
+}
+
+/// Names opl-data shares between several lifters, read from its
+/// `lifter-data/name-disambiguation.csv`. The entries of these lifters are told apart by a
+/// number, like "Thomas Martin #2"
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NameDisambiguation(HashMap<String, u16>);
+
+impl NameDisambiguation {
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let file: File = File::open(path).with_context(|| format!("cannot open name disambiguation \"{}\"", path.display()))?;
+
+        let mut names: Self = Self::default();
+        names
+            .extend(file)
+            .with_context(|| format!("invalid name disambiguation \"{}\"", path.display()))?;
+
+        Ok(names)
+    }
+
+    fn extend(&mut self, reader: impl Read) -> Result<()> {
+        for shared_name in ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader).deserialize() {
+            let shared_name: SharedName = shared_name?;
+            self.0.insert(Username::from_str(&shared_name.name)?.key(), shared_name.count);
+        }
+
+        Ok(())
+    }
+
+    /// Whether the name of an entry can't tell its lifter apart: the name is shared and the
+    /// entry has no number, or a number above the count of lifters sharing it
+    pub fn is_ambiguous(&self, name: &Username) -> bool {
+        if self.0.is_empty() {
+            return false;
+        }
+
+        match (self.0.get(&name.key()), name.disambiguation) {
+            (Some(_), None) => true,
+            (Some(&count), Some(number)) => number == 0 || number > count,
+            (None, _) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use std::str::FromStr;
+
+    use crate::types::Username;
+
+    use super::NameDisambiguation;
+
+    fn names() -> NameDisambiguation {
+        let mut names: NameDisambiguation = NameDisambiguation::default();
+        names.extend("Name,Count\nThomas Martin,2\nLéa Dupré,3\n".as_bytes()).unwrap();
+        names
+    }
+
+    #[rstest]
+    #[case("Thomas Martin", true)]
+    #[case("thomas  martin", true)]
+    #[case("Thomas Martin #1", false)]
+    #[case("Thomas Martin #2", false)]
+    #[case("Thomas Martin #3", true)]
+    #[case("Lea Dupre", true)]
+    #[case("Léa Dupré #3", false)]
+    #[case("Open Lifter", false)]
+    #[case("Open Lifter #1", false)]
+    fn test_is_ambiguous(
+        #[case] name: &str,
+        #[case] expected: bool,
+    ) {
+        let name: Username = Username::from_str(name).unwrap();
+
+        assert_eq!(expected, names().is_ambiguous(&name));
+    }
+
+    #[test]
+    fn test_is_ambiguous_without_file() {
+        let name: Username = Username::from_str("Thomas Martin").unwrap();
+
+        assert!(!NameDisambiguation::default().is_ambiguous(&name));
+    }
+
+    #[rstest]
+    #[case("Name,Count\nThomas Martin,two\n")]
+    #[case("Name\nThomas Martin\n")]
+    fn test_extend_error(#[case] content: &str) {
+        let mut names: NameDisambiguation = NameDisambiguation::default();
+
+        assert!(names.extend(content.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+use anyhow::{bail, Result};
+use rust_decimal::Decimal;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use types::prelude::AgeDto;
+
+/// Age of a lifter at the date of a meet, a `.5` age means the lifter was one of two
+/// consecutive ages
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Age(pub Decimal);
+
+impl From<Age> for AgeDto {
+    fn from(value: Age) -> Self {
+        Self(value.0)
+    }
+}
+
+impl FromStr for Age {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let age: Decimal = s.parse()?;
+
+        if age.is_sign_negative() {
+            bail!("age \"{s}\" should be positive");
+        }
+
+        Ok(Self(age))
+    }
+}
+
+impl Display for Age {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Age {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(AgeVisitor)
+    }
+}
+
+struct AgeVisitor;
+
+impl Visitor<'_> for AgeVisitor {
+    type Value = Age;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an age like 23 or 23.5")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Self::Value::from_str(v).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
+    use std::str::FromStr;
+
+    use super::Age;
+
+    #[rstest]
+    #[case("23", Age(Decimal::new(23, 0)))]
+    #[case("23.5", Age(Decimal::new(235, 1)))]
+    #[case("0", Age(Decimal::new(0, 0)))]
+    fn test_from_str(
+        #[case] input: &str,
+        #[case] expected: Age,
+    ) {
+        assert_eq!(expected, Age::from_str(input).unwrap());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("-1")]
+    #[case("twenty")]
+    fn test_from_str_error(#[case] input: &str) {
+        assert!(Age::from_str(input).is_err());
+    }
+
+    #[rstest]
+    #[case("40", Age(Decimal::new(40, 0)))]
+    #[case("40.5", Age(Decimal::new(405, 1)))]
+    fn test_deserialize(
+        #[case] input: &'static str,
+        #[case] expected: Age,
+    ) {
+        assert_de_tokens(&expected, &[Token::Str(input)]);
+    }
+
+    #[test]
+    fn test_deserialize_error() {
+        assert_de_tokens_error::<Age>(&[Token::Str("-1")], "age \"-1\" should be positive");
+    }
+}
@@ -0,0 +1,129 @@
+use serde::Deserialize;
+use strum_macros::{Display, EnumIter};
+use types::prelude::AgeClassDto;
+
+/// Age range of a lifter at the date of a meet
+#[derive(Clone, Copy, Debug, Display, Deserialize, Eq, EnumIter, PartialEq)]
+pub enum AgeClass {
+    #[strum(to_string = "5-12")]
+    #[serde(rename(deserialize = "5-12"))]
+    Age5To12,
+
+    #[strum(to_string = "13-15")]
+    #[serde(rename(deserialize = "13-15"))]
+    Age13To15,
+
+    #[strum(to_string = "16-17")]
+    #[serde(rename(deserialize = "16-17"))]
+    Age16To17,
+
+    #[strum(to_string = "18-19")]
+    #[serde(rename(deserialize = "18-19"))]
+    Age18To19,
+
+    #[strum(to_string = "20-23")]
+    #[serde(rename(deserialize = "20-23"))]
+    Age20To23,
+
+    #[strum(to_string = "24-34")]
+    #[serde(rename(deserialize = "24-34"))]
+    Age24To34,
+
+    #[strum(to_string = "35-39")]
+    #[serde(rename(deserialize = "35-39"))]
+    Age35To39,
+
+    #[strum(to_string = "40-44")]
+    #[serde(rename(deserialize = "40-44"))]
+    Age40To44,
+
+    #[strum(to_string = "45-49")]
+    #[serde(rename(deserialize = "45-49"))]
+    Age45To49,
+
+    #[strum(to_string = "50-54")]
+    #[serde(rename(deserialize = "50-54"))]
+    Age50To54,
+
+    #[strum(to_string = "55-59")]
+    #[serde(rename(deserialize = "55-59"))]
+    Age55To59,
+
+    #[strum(to_string = "60-64")]
+    #[serde(rename(deserialize = "60-64"))]
+    Age60To64,
+
+    #[strum(to_string = "65-69")]
+    #[serde(rename(deserialize = "65-69"))]
+    Age65To69,
+
+    #[strum(to_string = "70-74")]
+    #[serde(rename(deserialize = "70-74"))]
+    Age70To74,
+
+    #[strum(to_string = "75-79")]
+    #[serde(rename(deserialize = "75-79"))]
+    Age75To79,
+
+    #[strum(to_string = "80-999")]
+    #[serde(rename(deserialize = "80-999"))]
+    Age80To999,
+}
+
+impl From<AgeClass> for AgeClassDto {
+    fn from(value: AgeClass) -> Self {
+        match value {
+            AgeClass::Age5To12 => Self::Age5To12,
+            AgeClass::Age13To15 => Self::Age13To15,
+            AgeClass::Age16To17 => Self::Age16To17,
+            AgeClass::Age18To19 => Self::Age18To19,
+            AgeClass::Age20To23 => Self::Age20To23,
+            AgeClass::Age24To34 => Self::Age24To34,
+            AgeClass::Age35To39 => Self::Age35To39,
+            AgeClass::Age40To44 => Self::Age40To44,
+            AgeClass::Age45To49 => Self::Age45To49,
+            AgeClass::Age50To54 => Self::Age50To54,
+            AgeClass::Age55To59 => Self::Age55To59,
+            AgeClass::Age60To64 => Self::Age60To64,
+            AgeClass::Age65To69 => Self::Age65To69,
+            AgeClass::Age70To74 => Self::Age70To74,
+            AgeClass::Age75To79 => Self::Age75To79,
+            AgeClass::Age80To999 => Self::Age80To999,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_test::{assert_de_tokens, Token};
+
+    use super::AgeClass;
+
+    #[rstest]
+    #[case("5-12", AgeClass::Age5To12)]
+    #[case("13-15", AgeClass::Age13To15)]
+    #[case("16-17", AgeClass::Age16To17)]
+    #[case("18-19", AgeClass::Age18To19)]
+    #[case("20-23", AgeClass::Age20To23)]
+    #[case("24-34", AgeClass::Age24To34)]
+    #[case("35-39", AgeClass::Age35To39)]
+    #[case("40-44", AgeClass::Age40To44)]
+    #[case("45-49", AgeClass::Age45To49)]
+    #[case("50-54", AgeClass::Age50To54)]
+    #[case("55-59", AgeClass::Age55To59)]
+    #[case("60-64", AgeClass::Age60To64)]
+    #[case("65-69", AgeClass::Age65To69)]
+    #[case("70-74", AgeClass::Age70To74)]
+    #[case("75-79", AgeClass::Age75To79)]
+    #[case("80-999", AgeClass::Age80To999)]
+    fn test_deserialize(
+        #[case] input: &'static str,
+        #[case] expected: AgeClass,
+    ) {
+        assert_de_tokens(
+            &expected,
+            &[Token::UnitVariant { name: "AgeClass", variant: input }]
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use serde::Deserialize;
+use strum_macros::{Display, EnumIter};
+use types::prelude::BirthYearClassDto;
+
+/// Age range reached by a lifter during the year of a meet, used by the IPF federations
+#[derive(Clone, Copy, Debug, Display, Deserialize, Eq, EnumIter, PartialEq)]
+pub enum BirthYearClass {
+    #[strum(to_string = "14-18")]
+    #[serde(rename(deserialize = "14-18"))]
+    Age14To18,
+
+    #[strum(to_string = "19-23")]
+    #[serde(rename(deserialize = "19-23"))]
+    Age19To23,
+
+    #[strum(to_string = "24-39")]
+    #[serde(rename(deserialize = "24-39"))]
+    Age24To39,
+
+    #[strum(to_string = "40-49")]
+    #[serde(rename(deserialize = "40-49"))]
+    Age40To49,
+
+    #[strum(to_string = "50-59")]
+    #[serde(rename(deserialize = "50-59"))]
+    Age50To59,
+
+    #[strum(to_string = "60-69")]
+    #[serde(rename(deserialize = "60-69"))]
+    Age60To69,
+
+    #[strum(to_string = "70-999")]
+    #[serde(rename(deserialize = "70-999"))]
+    Age70To999,
+}
+
+impl From<BirthYearClass> for BirthYearClassDto {
+    fn from(value: BirthYearClass) -> Self {
+        match value {
+            BirthYearClass::Age14To18 => Self::Age14To18,
+            BirthYearClass::Age19To23 => Self::Age19To23,
+            BirthYearClass::Age24To39 => Self::Age24To39,
+            BirthYearClass::Age40To49 => Self::Age40To49,
+            BirthYearClass::Age50To59 => Self::Age50To59,
+            BirthYearClass::Age60To69 => Self::Age60To69,
+            BirthYearClass::Age70To999 => Self::Age70To999,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_test::{assert_de_tokens, Token};
+
+    use super::BirthYearClass;
+
+    #[rstest]
+    #[case("14-18", BirthYearClass::Age14To18)]
+    #[case("19-23", BirthYearClass::Age19To23)]
+    #[case("24-39", BirthYearClass::Age24To39)]
+    #[case("40-49", BirthYearClass::Age40To49)]
+    #[case("50-59", BirthYearClass::Age50To59)]
+    #[case("60-69", BirthYearClass::Age60To69)]
+    #[case("70-999", BirthYearClass::Age70To999)]
+    fn test_deserialize(
+        #[case] input: &'static str,
+        #[case] expected: BirthYearClass,
+    ) {
+        assert_de_tokens(
+            &expected,
+            &[Token::UnitVariant { name: "BirthYearClass", variant: input }]
+        );
+    }
+}
@@ -1,47 +1,134 @@
-use serde::Deserialize;
-use strum_macros::EnumIter;
+use anyhow::{anyhow, Result};
+use log::warn;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::{self, Display};
+use std::str::FromStr;
 use types::prelude::CountryDto;
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, EnumIter, Eq, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum Country {
-    #[strum(to_string = "France")]
-    #[serde(rename(deserialize = "France"))]
-    FRANCE,
-
-    #[strum(to_string = "Other")]
-    #[serde(other)]
-    #[default]
-    OTHER,
+/// Country as named by the opl-data tree, e.g. `France` or `N.Ireland`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Country(pub CountryDto);
+
+impl Country {
+    /// Optional country of a csv column. opl-data adds countries over time, an unknown one is
+    /// read as no country with a warning rather than dropping the whole meet
+    pub fn deserialize_or_none<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Self>, D::Error> {
+        let value: Option<String> = Option::deserialize(deserializer)?;
+
+        match value.as_deref().map(str::trim) {
+            None | Some("") => Ok(None),
+            Some(value) => match Self::from_str(value) {
+                Ok(country) => Ok(Some(country)),
+                Err(e) => {
+                    warn!("{e}, read as no country");
+                    Ok(None)
+                },
+            },
+        }
+    }
 }
 
 impl From<Country> for CountryDto {
     fn from(value: Country) -> Self {
-        match value {
-            Country::FRANCE => Self::FRANCE,
-            Country::OTHER => Self::OTHER,
-        }
+        value.0
+    }
+}
+
+impl FromStr for Country {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CountryDto::from_str(s.trim())
+            .map(Self)
+            .map_err(|_| anyhow!("unknown country \"{s}\""))
+    }
+}
+
+impl Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Country {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(CountryVisitor)
+    }
+}
+
+struct CountryVisitor;
+
+impl Visitor<'_> for CountryVisitor {
+    type Value = Country;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a country name")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Self::Value::from_str(v).map_err(E::custom)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use csv::ReaderBuilder;
+    use pretty_assertions::assert_eq;
     use rstest::rstest;
-    use serde_test::{assert_de_tokens, Token};
+    use serde::Deserialize;
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
+    use types::prelude::CountryDto;
 
     use super::Country;
 
+    #[derive(Debug, Deserialize)]
+    struct Row {
+        #[serde(rename(deserialize = "Country"))]
+        #[serde(default, deserialize_with = "Country::deserialize_or_none")]
+        country: Option<Country>,
+    }
+
     #[rstest]
-    #[case("France", Country::FRANCE)]
-    #[case("Some value", Country::OTHER)]
+    #[case("France", CountryDto::France, "FR")]
+    #[case("USA", CountryDto::USA, "US")]
+    #[case("N.Ireland", CountryDto::NorthernIreland, "GB-NIR")]
+    #[case("Bosnia and Herzegovina", CountryDto::BosniaAndHerzegovina, "BA")]
+    #[case("USSR", CountryDto::USSR, "SUHH")]
     fn test_deserialize(
         #[case] input: &'static str,
-        #[case] expected: Country
+        #[case] expected: CountryDto,
+        #[case] iso: &str,
     ) {
-        assert_de_tokens(
-            &expected, 
-            &[Token::UnitVariant { name: "Country", variant: input }]
-        );
+        assert_de_tokens(&Country(expected), &[Token::Str(input)]);
+        assert_eq!(iso, expected.iso());
+        assert_eq!(Some(expected), CountryDto::from_iso(iso));
     }
-}
 
+    #[rstest]
+    #[case("France", Some(Country(CountryDto::France)))]
+    #[case("", None)]
+    #[case("Atlantis", None)]
+    fn test_deserialize_or_none(
+        #[case] input: &str,
+        #[case] expected: Option<Country>,
+    ) {
+        let data: String = format!("Name,Country\nName,{input}\n");
+        let row: Row = ReaderBuilder::new()
+            .from_reader(data.as_bytes())
+            .deserialize()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(expected, row.country);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("france")]
+    #[case("Some value")]
+    fn test_deserialize_error(#[case] input: &'static str) {
+        assert_de_tokens_error::<Country>(&[Token::Str(input)], &format!("unknown country \"{input}\""));
+    }
+}
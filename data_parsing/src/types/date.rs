@@ -0,0 +1,94 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use types::prelude::DateDto;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Date(pub NaiveDate);
+
+impl From<Date> for DateDto {
+    fn from(value: Date) -> Self {
+        Self(value.0)
+    }
+}
+
+impl FromStr for Date {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(NaiveDate::parse_from_str(s, DATE_FORMAT)?))
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.format(DATE_FORMAT))
+    }
+}
+
+struct DateVisitor;
+
+impl Visitor<'_> for DateVisitor {
+    type Value = Date;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a date formatted as YYYY-MM-DD")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Date::from_str(value).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(DateVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::Date;
+
+    #[rstest]
+    #[case("2024-03-16", NaiveDate::from_ymd_opt(2024, 3, 16).unwrap())]
+    #[case("1999-12-31", NaiveDate::from_ymd_opt(1999, 12, 31).unwrap())]
+    #[case("2020-02-29", NaiveDate::from_ymd_opt(2020, 2, 29).unwrap())]
+    fn test_deserialize(
+        #[case] input: &str,
+        #[case] expected: NaiveDate,
+    ) {
+        let result: Result<Date> = input.parse::<Date>();
+
+        assert!(result.is_ok());
+        assert_eq!(Date(expected), result.unwrap());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("16/03/2024")]
+    #[case("2021-02-29")]
+    #[case("2024-13-01")]
+    fn test_deserialize_error(#[case] input: &str) {
+        let result: Result<Date> = input.parse::<Date>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let date: Date = Date(NaiveDate::from_ymd_opt(2024, 3, 6).unwrap());
+
+        assert_eq!("2024-03-06", date.to_string());
+    }
+}
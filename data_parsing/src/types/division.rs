@@ -1,62 +1,50 @@
-use serde::Deserialize;
-use strum_macros::{Display, EnumIter};
+use strum_macros::{Display, EnumIter, EnumString};
 use types::prelude::DivisionDto;
 
-#[derive(Clone, Copy, Debug, Display, Deserialize, Eq, EnumIter, PartialEq)]
-#[serde(rename_all = "lowercase")]
+/// Canonical divisions, the raw division of an entry is resolved to one of them through the
+/// division aliases
+#[derive(Clone, Copy, Debug, Default, Display, Eq, EnumIter, EnumString, PartialEq)]
 pub enum Division {
     #[strum(to_string = "Open")]
-    #[serde(rename(deserialize = "Open"))]
     Open,
 
     #[strum(to_string = "Guest")]
-    #[serde(rename(deserialize = "Guest"))]
-    #[serde(rename(deserialize = "Hors Match"))]
-    #[serde(rename(deserialize = "Prime Time"))]
     G,
 
     #[strum(to_string = "Cadet")]
-    #[serde(rename(deserialize = "Cadet"))]
     Cadet,
 
     #[strum(to_string = "Elite")]
-    #[serde(rename(deserialize = "Elite"))]
     Elite,
 
     #[strum(to_string = "Sub-Juniors")]
-    #[serde(rename(deserialize = "Sub-Juniors"))]
-    #[serde(rename(deserialize = "Subjunior/Junior"))]
     SubJuniors,
 
     #[strum(to_string = "Juniors")]
-    #[serde(rename(deserialize = "Juniors"))]
-    #[serde(rename(deserialize = "Jeunes"))]
     Juniors,
 
     #[strum(to_string = "Masters")]
-    #[serde(rename(deserialize = "Masters"))]
     Masters,
 
     #[strum(to_string = "Seniors")]
-    #[serde(rename(deserialize = "Seniors"))]
-    #[serde(rename(deserialize = "Senior/Master"))]
     Seniors,
 
     #[strum(to_string = "Masters 1")]
-    #[serde(rename(deserialize = "Masters 1"))]
     Masters1,
 
     #[strum(to_string = "Masters 2")]
-    #[serde(rename(deserialize = "Masters 2"))]
     Masters2,
 
     #[strum(to_string = "Masters 3")]
-    #[serde(rename(deserialize = "Masters 3"))]
     Masters3,
 
     #[strum(to_string = "Masters 4")]
-    #[serde(rename(deserialize = "Masters 4"))]
     Masters4,
+
+    /// Raw division without alias, the raw text is kept on the entry
+    #[default]
+    #[strum(to_string = "Unknown")]
+    Unknown,
 }
 
 impl From<Division> for DivisionDto {
@@ -74,22 +62,24 @@ impl From<Division> for DivisionDto {
             Division::Masters2 => Self::Masters2,
             Division::Masters3 => Self::Masters3,
             Division::Masters4 => Self::Masters4,
+            Division::Unknown => Self::Unknown,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use pretty_assertions::assert_eq;
     use rstest::rstest;
-    use serde_test::{assert_de_tokens, Token};
+    use std::str::FromStr;
+    use strum::IntoEnumIterator;
 
     use super::Division;
 
     #[rstest]
     #[case("Cadet", Division::Cadet)]
     #[case("Elite", Division::Elite)]
-    #[case("Hors Match", Division::G)]
-    #[case("Jeunes", Division::Juniors)]
+    #[case("Guest", Division::G)]
     #[case("Juniors", Division::Juniors)]
     #[case("Masters", Division::Masters)]
     #[case("Masters 1", Division::Masters1)]
@@ -97,17 +87,28 @@ mod tests {
     #[case("Masters 3", Division::Masters3)]
     #[case("Masters 4", Division::Masters4)]
     #[case("Open", Division::Open)]
-    #[case("Prime Time", Division::G)]
-    #[case("Senior/Master", Division::Seniors)]
     #[case("Seniors", Division::Seniors)]
     #[case("Sub-Juniors", Division::SubJuniors)]
-    fn test_deserialize(
-        #[case] input: &'static str,
+    #[case("Unknown", Division::Unknown)]
+    fn test_from_str(
+        #[case] input: &str,
         #[case] expected: Division,
     ) {
-        assert_de_tokens(
-            &expected, 
-            &[Token::UnitVariant { name: "Division", variant: input }]
-        );
+        assert_eq!(expected, Division::from_str(input).unwrap());
+    }
+
+    #[rstest]
+    #[case("Hors Match")]
+    #[case("Jeunes")]
+    #[case("")]
+    fn test_from_str_error(#[case] input: &str) {
+        assert!(Division::from_str(input).is_err());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        for division in Division::iter() {
+            assert_eq!(division, Division::from_str(&division.to_string()).unwrap());
+        }
     }
 }
@@ -1,22 +1,56 @@
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use types::prelude::EntryDto;
 
-use crate::types::{Division, Equipment, Sex, Username, Weight, WeightClass};
+use crate::types::{Age, AgeClass, BirthYearClass, Country, Division, Equipment, Event, Place, Sex, Username, Weight, WeightClass};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 pub struct Entry {
+    #[serde(rename(deserialize = "Place"))]
+    pub place: Option<Place>,
+
     #[serde(rename(deserialize = "Name"))]
     pub name: Username,
 
-    #[serde(rename(deserialize = "Division"))]
+    #[serde(rename(deserialize = "CyrillicName"))]
+    #[serde(default)]
+    pub cyrillic_name: Option<String>,
+
+    #[serde(rename(deserialize = "JapaneseName"))]
+    #[serde(default)]
+    pub japanese_name: Option<String>,
+
+    /// Resolved from the raw division once the federation of the meet is known
+    #[serde(skip)]
     pub division: Division,
 
+    #[serde(rename(deserialize = "Division"))]
+    pub division_raw: String,
+
     #[serde(rename(deserialize = "Equipment"))]
     pub equipment: Equipment,
 
+    #[serde(rename(deserialize = "Event"))]
+    pub event: Event,
+
     #[serde(rename(deserialize = "Sex"))]
     pub sex: Sex,
 
+    #[serde(rename(deserialize = "Country"))]
+    #[serde(default, deserialize_with = "Country::deserialize_or_none")]
+    pub country: Option<Country>,
+
+    #[serde(rename(deserialize = "Age"))]
+    #[serde(default)]
+    pub age: Option<Age>,
+
+    #[serde(rename(deserialize = "AgeClass"))]
+    #[serde(default)]
+    pub age_class: Option<AgeClass>,
+
+    #[serde(rename(deserialize = "BirthYearClass"))]
+    #[serde(default)]
+    pub birth_year_class: Option<BirthYearClass>,
+
     #[serde(rename(deserialize = "BodyweightKg"))]
     pub bodyweight: Weight,
 
@@ -32,6 +66,10 @@ pub struct Entry {
     #[serde(rename(deserialize = "Squat3Kg"))]
     pub squat3: Option<Weight>,
 
+    #[serde(rename(deserialize = "Squat4Kg"))]
+    #[serde(default)]
+    pub squat4: Option<Weight>,
+
     #[serde(rename(deserialize = "Best3SquatKg"))]
     pub best3squat: Option<Weight>,
 
@@ -44,6 +82,10 @@ pub struct Entry {
     #[serde(rename(deserialize = "Bench3Kg"))]
     pub bench3: Option<Weight>,
 
+    #[serde(rename(deserialize = "Bench4Kg"))]
+    #[serde(default)]
+    pub bench4: Option<Weight>,
+
     #[serde(rename(deserialize = "Best3BenchKg"))]
     pub best3bench: Option<Weight>,
 
@@ -56,39 +98,114 @@ pub struct Entry {
     #[serde(rename(deserialize = "Deadlift3Kg"))]
     pub deadlift3: Option<Weight>,
 
+    #[serde(rename(deserialize = "Deadlift4Kg"))]
+    #[serde(default)]
+    pub deadlift4: Option<Weight>,
+
     #[serde(rename(deserialize = "Best3DeadliftKg"))]
     pub best3deadlift: Option<Weight>,
 
     #[serde(rename(deserialize = "TotalKg"))]
     pub total: Option<Weight>,
+
+    #[serde(rename(deserialize = "Tested"))]
+    #[serde(default, deserialize_with = "yes_as_true")]
+    pub tested: bool,
+}
+
+/// The Tested column is "Yes" for drug tested entries and empty otherwise
+fn yes_as_true<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    let value: Option<String> = Option::deserialize(deserializer)?;
+
+    match value.as_deref().map(str::trim) {
+        None | Some("") => Ok(false),
+        Some("Yes") => Ok(true),
+        Some(value) => Err(serde::de::Error::custom(format!("tested should be \"Yes\" or empty, got \"{value}\""))),
+    }
 }
 
 impl From<Entry> for EntryDto {
     fn from(value: Entry) -> Self {
         Self {
             rank: None,
+            lifter_id: None,
+            match_score: None,
+            place: value.place.map(Place::into),
+            federation: None,
             name: value.name.into(),
+            cyrillic_name: value.cyrillic_name,
+            japanese_name: value.japanese_name,
             division: value.division.into(),
+            division_raw: value.division_raw,
             equipment: value.equipment.into(),
+            event: value.event.into(),
             sex: value.sex.into(),
+            country: value.country.map(Country::into),
+            age: value.age.map(Age::into),
+            age_class: value.age_class.map(AgeClass::into),
+            birth_year_class: value.birth_year_class.map(BirthYearClass::into),
             bodyweight: value.bodyweight.into(),
             weight_class: value.weight_class.map(WeightClass::into),
             squat1: value.squat1.map(Weight::into),
             squat2: value.squat2.map(Weight::into),
             squat3: value.squat3.map(Weight::into),
-            squat4: None,
+            squat4: value.squat4.map(Weight::into),
             bench1: value.bench1.map(Weight::into),
             bench2: value.bench2.map(Weight::into),
             bench3: value.bench3.map(Weight::into),
-            bench4: None,
+            bench4: value.bench4.map(Weight::into),
             deadlift1: value.deadlift1.map(Weight::into),
             deadlift2: value.deadlift2.map(Weight::into),
             deadlift3: value.deadlift3.map(Weight::into),
-            deadlift4: None,
+            deadlift4: value.deadlift4.map(Weight::into),
             best_squat: value.best3squat.map(Weight::into),
             best_bench: value.best3bench.map(Weight::into),
             best_deadlift: value.best3deadlift.map(Weight::into),
             total: value.total.map(Weight::into),
+            tested: value.tested,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use csv::ReaderBuilder;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde::Deserialize;
+
+    use super::yes_as_true;
+
+    #[derive(Debug, Deserialize)]
+    struct Row {
+        #[serde(rename(deserialize = "Tested"))]
+        #[serde(default, deserialize_with = "yes_as_true")]
+        tested: bool,
+    }
+
+    fn parse(input: &str) -> csv::Result<Row> {
+        let data: String = format!("Name,Tested\nName,{input}\n");
+        ReaderBuilder::new()
+            .from_reader(data.as_bytes())
+            .deserialize()
+            .next()
+            .unwrap()
+    }
+
+    #[rstest]
+    #[case("Yes", true)]
+    #[case("", false)]
+    fn test_yes_as_true(
+        #[case] input: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(expected, parse(input).unwrap().tested);
+    }
+
+    #[rstest]
+    #[case("No")]
+    #[case("yes")]
+    fn test_yes_as_true_error(#[case] input: &str) {
+        assert!(parse(input).is_err());
+    }
+}
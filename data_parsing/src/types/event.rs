@@ -0,0 +1,86 @@
+use serde::Deserialize;
+use strum_macros::{Display, EnumIter};
+use types::prelude::EventDto;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Display, Deserialize, Eq, EnumIter, PartialEq)]
+pub enum Event {
+    #[strum(to_string = "SBD")]
+    #[serde(rename(deserialize = "SBD"))]
+    SBD,
+
+    #[strum(to_string = "BD")]
+    #[serde(rename(deserialize = "BD"))]
+    BD,
+
+    #[strum(to_string = "SD")]
+    #[serde(rename(deserialize = "SD"))]
+    SD,
+
+    #[strum(to_string = "SB")]
+    #[serde(rename(deserialize = "SB"))]
+    SB,
+
+    #[strum(to_string = "S")]
+    #[serde(rename(deserialize = "S"))]
+    S,
+
+    #[strum(to_string = "B")]
+    #[serde(rename(deserialize = "B"))]
+    B,
+
+    #[strum(to_string = "D")]
+    #[serde(rename(deserialize = "D"))]
+    D,
+}
+
+impl From<Event> for EventDto {
+    fn from(value: Event) -> Self {
+        match value {
+            Event::SBD => Self::SBD,
+            Event::BD => Self::BD,
+            Event::SD => Self::SD,
+            Event::SB => Self::SB,
+            Event::S => Self::S,
+            Event::B => Self::B,
+            Event::D => Self::D,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
+
+    use super::Event;
+
+    #[rstest]
+    #[case("SBD", Event::SBD)]
+    #[case("BD", Event::BD)]
+    #[case("SD", Event::SD)]
+    #[case("SB", Event::SB)]
+    #[case("S", Event::S)]
+    #[case("B", Event::B)]
+    #[case("D", Event::D)]
+    fn test_deserialize(
+        #[case] input: &'static str,
+        #[case] expected: Event,
+    ) {
+        assert_de_tokens(
+            &expected,
+            &[Token::UnitVariant { name: "Event", variant: input }]
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("sbd")]
+    #[case("DBS")]
+    fn test_deserialize_error(#[case] input: &'static str) {
+        assert_de_tokens_error::<Event>(
+            &[Token::UnitVariant { name: "Event", variant: input }],
+            &format!("unknown variant `{input}`, expected one of `SBD`, `BD`, `SD`, `SB`, `S`, `B`, `D`"),
+        );
+    }
+}
@@ -1,64 +1,86 @@
-use serde::Deserialize;
-use strum_macros::{Display, EnumIter};
+use anyhow::{bail, Result};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::{self, Display};
+use std::str::FromStr;
 use types::prelude::FederationDto;
 
-#[derive(Copy, Clone, Debug, Deserialize, Default, Display, Eq, PartialEq, EnumIter)]
-#[serde(rename_all = "lowercase")]
-pub enum Federation {
-    #[strum(to_string = "FFForce")]
-    #[serde(rename(deserialize = "FFForce"))]
-    FFForce,
-
-    #[strum(to_string = "EPF")]
-    #[serde(rename(deserialize = "EPF"))]
-    EPF,
-
-    #[strum(to_string = "IPF")]
-    #[serde(rename(deserialize = "IPF"))]
-    IPF,
-
-    #[strum(to_string = "FFHMFAC")]
-    #[serde(rename(deserialize = "FFHMFAC"))]
-    FFHMFAC,
-
-    #[strum(to_string = "Other")]
-    #[serde(other)]
-    #[default]
-    OTHER,
-}
+/// Code of a federation as written in the opl-data tree, e.g. `FFForce` or `USAPL`
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Federation(pub String);
 
 impl From<Federation> for FederationDto {
     fn from(value: Federation) -> Self {
-        match value {
-            Federation::FFForce => Self::FFForce,
-            Federation::EPF => Self::EPF,
-            Federation::IPF => Self::IPF,
-            Federation::FFHMFAC => Self::FFHMFAC,
-            Federation::OTHER => Self::OTHER,
+        Self(value.0)
+    }
+}
+
+impl FromStr for Federation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code: &str = s.trim();
+
+        if code.is_empty() {
+            bail!("federation should not be empty");
         }
+
+        Ok(Self(code.to_string()))
+    }
+}
+
+impl Display for Federation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Federation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FederationVisitor)
+    }
+}
+
+struct FederationVisitor;
+
+impl Visitor<'_> for FederationVisitor {
+    type Value = Federation;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a federation code")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Self::Value::from_str(v).map_err(E::custom)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
-    use serde_test::{assert_de_tokens, Token};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
 
     use super::Federation;
 
     #[rstest]
-    #[case("FFForce", Federation::FFForce)]
-    #[case("EPF", Federation::EPF)]
-    #[case("IPF", Federation::IPF)]
-    #[case("FFHMFAC", Federation::FFHMFAC)]
-    #[case("Some federation", Federation::OTHER)]
+    #[case("FFForce", "FFForce")]
+    #[case("IPF", "IPF")]
+    #[case("USAPL", "USAPL")]
+    #[case(" WRPF ", "WRPF")]
     fn test_deserialize(
         #[case] input: &'static str,
-        #[case] expected: Federation,
+        #[case] expected: &str,
     ) {
         assert_de_tokens(
-            &expected, 
-            &[Token::UnitVariant { name: "Federation", variant: input }]
+            &Federation(expected.to_string()),
+            &[Token::Str(input)]
         );
     }
+
+    #[rstest]
+    #[case("")]
+    #[case("  ")]
+    fn test_deserialize_error(#[case] input: &'static str) {
+        assert_de_tokens_error::<Federation>(&[Token::Str(input)], "federation should not be empty");
+    }
 }
@@ -5,22 +5,35 @@ use crate::types::Entry;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Meet {
+    pub path: String,
+    pub hash: String,
     pub data: MeetData,
     pub entries: Vec<Entry>,
 }
 
 impl Meet {
     #[must_use]
-    pub const fn new(data: MeetData, entries: Vec<Entry>) -> Self {
-        Self { data, entries }
+    pub const fn new(path: String, hash: String, data: MeetData, entries: Vec<Entry>) -> Self {
+        Self { path, hash, data, entries }
     }
 }
 
 impl From<Meet> for MeetDto {
     fn from(value: Meet) -> Self {
+        let data: MeetDataDto = value.data.into();
+        let entries: Vec<EntryDto> = value.entries
+            .into_iter()
+            .map(|entry| EntryDto {
+                federation: Some(data.federation.clone()),
+                ..entry.into()
+            })
+            .collect();
+
         Self {
-            data: value.data.into(),
-            entries: value.entries.into_iter().map(EntryDto::from).collect(),
+            path: value.path,
+            hash: value.hash,
+            data,
+            entries,
         }
     }
 }
@@ -1,17 +1,16 @@
 use serde::Deserialize;
 use types::prelude::*;
 
-use crate::types::{Country, Federation};
+use crate::types::{Country, Date, Federation};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
 pub struct MeetData {
     #[serde(rename(deserialize = "Federation"))]
-    #[serde(default)]
     pub federation: Federation,
 
     #[serde(rename(deserialize = "MeetCountry"))]
-    #[serde(default)]
-    pub country: Country,
+    #[serde(default, deserialize_with = "Country::deserialize_or_none")]
+    pub country: Option<Country>,
 
     #[serde(rename(deserialize = "MeetState"))]
     #[serde(default)]
@@ -24,6 +23,10 @@ pub struct MeetData {
     #[serde(rename(deserialize = "MeetName"))]
     #[serde(default)]
     pub name: String,
+
+    #[serde(rename(deserialize = "Date"), alias = "MeetDate")]
+    #[serde(default)]
+    pub date: Option<Date>,
 }
 
 impl From<MeetData> for MeetDataDto {
@@ -31,9 +34,10 @@ impl From<MeetData> for MeetDataDto {
         Self {
             name: value.name,
             federation: value.federation.into(),
-            country: value.country.into(),
+            country: value.country.map(Country::into),
             state: value.state,
             town: value.town,
+            date: value.date.map(Date::into),
         }
     }
 }
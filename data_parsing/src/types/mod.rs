@@ -1,7 +1,12 @@
+mod age;
+mod age_class;
+mod birth_year_class;
 mod country;
+mod date;
 mod division;
 mod entry;
 mod equipment;
+mod event;
 mod federation;
 mod meet;
 mod meet_data;
@@ -11,10 +16,15 @@ mod username;
 mod weight;
 mod weight_class;
 
+pub use age::Age;
+pub use age_class::AgeClass;
+pub use birth_year_class::BirthYearClass;
 pub use country::Country;
+pub use date::Date;
 pub use division::Division;
 pub use entry::Entry;
 pub use equipment::Equipment;
+pub use event::Event;
 pub use federation::Federation;
 pub use meet::Meet;
 pub use meet_data::MeetData;
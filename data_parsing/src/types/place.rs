@@ -1,6 +1,11 @@
-use serde::Deserialize;
+use anyhow::{bail, Result};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use types::prelude::PlaceDto;
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum Place {
     /// The placing assigned to the entry.
     P(u8),
@@ -14,3 +19,103 @@ pub enum Place {
     #[default]
     NS,
 }
+
+impl From<Place> for PlaceDto {
+    fn from(value: Place) -> Self {
+        match value {
+            Place::P(place) => Self::P(place),
+            Place::G => Self::G,
+            Place::DQ => Self::DQ,
+            Place::DD => Self::DD,
+            Place::NS => Self::NS,
+        }
+    }
+}
+
+impl FromStr for Place {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "G" => Ok(Self::G),
+            "DQ" => Ok(Self::DQ),
+            "DD" => Ok(Self::DD),
+            "NS" => Ok(Self::NS),
+            _ => match s.parse::<u8>() {
+                Ok(0) => bail!("place \"{s}\" should be greater than 0"),
+                Ok(place) => Ok(Self::P(place)),
+                Err(e) => bail!("place \"{s}\" is invalid: {e}"),
+            },
+        }
+    }
+}
+
+impl Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::P(place) => write!(f, "{place}"),
+            Self::G => write!(f, "G"),
+            Self::DQ => write!(f, "DQ"),
+            Self::DD => write!(f, "DD"),
+            Self::NS => write!(f, "NS"),
+        }
+    }
+}
+
+struct PlaceVisitor;
+
+impl Visitor<'_> for PlaceVisitor {
+    type Value = Place;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a placing, G, DQ, DD or NS")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Place::from_str(value).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for Place {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(PlaceVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::Place;
+
+    #[rstest]
+    #[case("1", Place::P(1))]
+    #[case("12", Place::P(12))]
+    #[case("G", Place::G)]
+    #[case("DQ", Place::DQ)]
+    #[case("DD", Place::DD)]
+    #[case("NS", Place::NS)]
+    fn test_deserialize(
+        #[case] input: &str,
+        #[case] expected: Place,
+    ) {
+        let result: Result<Place> = input.parse::<Place>();
+
+        assert!(result.is_ok());
+        assert_eq!(expected, result.unwrap());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("0")]
+    #[case("-1")]
+    #[case("dq")]
+    #[case("First")]
+    fn test_deserialize_error(#[case] input: &str) {
+        let result: Result<Place> = input.parse::<Place>();
+
+        assert!(result.is_err());
+    }
+}
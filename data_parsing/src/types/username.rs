@@ -9,6 +9,7 @@ use std::str::FromStr;
 pub struct Username {
     pub name: String,
     parts: Vec<String>,
+    pub disambiguation: Option<u16>,
 }
 
 impl Username {
@@ -16,21 +17,30 @@ impl Username {
         Self {
             name: String::new(),
             parts: Vec::new(),
+            disambiguation: None,
         }
 }
 
     #[must_use]
-    pub fn new(name: &str, parts: Vec<String>) -> Self {
+    pub fn new(name: &str, parts: Vec<String>, disambiguation: Option<u16>) -> Self {
         Self {
             name: name.to_string(),
             parts,
+            disambiguation,
         }
     }
+
+    /// Normalized words of the name without its disambiguation number, the homonyms of
+    /// opl-data share it
+    #[must_use]
+    pub fn key(&self) -> String {
+        self.parts.join(" ")
+    }
 }
 
 impl From<Username> for UsernameDto {
     fn from(value: Username) -> Self {
-        Self::new(&value.name, value.parts)
+        Self::new(&value.name, value.parts, value.disambiguation)
     }
 }
 
@@ -38,13 +48,9 @@ impl FromStr for Username {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let parts: Vec<String> = s
-            .split_whitespace()
-            .filter(|w| !w.is_empty())
-            .map(str::to_lowercase)
-            .collect();
+        let (parts, disambiguation): (Vec<String>, Option<u16>) = UsernameDto::split(s);
 
-        Ok(Self::new(s, parts))
+        Ok(Self::new(s, parts, disambiguation))
     }
 }
 
@@ -54,7 +60,7 @@ impl PartialEq for Username {
     /// `self` is the entry in the opl-data csv files
     /// `other` is the powerlifter name requested
     fn eq(&self, other: &Self) -> bool {
-        self.parts.eq(&other.parts)
+        self.parts.eq(&other.parts) && self.disambiguation == other.disambiguation
     }
 }
 
@@ -93,10 +99,19 @@ mod tests {
     use super::Username;
 
     #[rstest]
-    #[case("a b", Username::new("a b", vec!["a".to_string(), "b".to_string()]))]
-    #[case("A B", Username::new("A B", vec!["a".to_string(), "b".to_string()]))]
-    #[case("a  b", Username::new("a b", vec!["a".to_string(), "b".to_string()]))]
-    #[case("a b c", Username::new("a b c", vec!["a".to_string(), "b".to_string(), "c".to_string()]))]
+    #[case("a b", Username::new("a b", vec!["a".to_string(), "b".to_string()], None))]
+    #[case("A B", Username::new("A B", vec!["a".to_string(), "b".to_string()], None))]
+    #[case("a  b", Username::new("a b", vec!["a".to_string(), "b".to_string()], None))]
+    #[case("a b c", Username::new("a b c", vec!["a".to_string(), "b".to_string(), "c".to_string()], None))]
+    #[case("a b #2", Username::new("a b #2", vec!["a".to_string(), "b".to_string()], Some(2)))]
+    #[case("a b #12", Username::new("a b #12", vec!["a".to_string(), "b".to_string()], Some(12)))]
+    #[case("a #b", Username::new("a #b", vec!["a".to_string(), "#b".to_string()], None))]
+    #[case("a #1 b", Username::new("a #1 b", vec!["a".to_string(), "#1".to_string(), "b".to_string()], None))]
+    #[case("Léa Dupré-O'Neil", Username::new("Léa Dupré-O'Neil", vec!["lea".to_string(), "dupre".to_string(), "oneil".to_string()], None))]
+    #[case("ÉMILIE Straße #3", Username::new("ÉMILIE Straße #3", vec!["emilie".to_string(), "strasse".to_string()], Some(3)))]
+    #[case("Jean–Pierre Łukasz", Username::new("Jean–Pierre Łukasz", vec!["jean".to_string(), "pierre".to_string(), "lukasz".to_string()], None))]
+    #[case("Иван Петров", Username::new("Иван Петров", vec!["иван".to_string(), "петров".to_string()], None))]
+    #[case("山田 ガク", Username::new("山田 ガク", vec!["山田".to_string(), "ガク".to_string()], None))]
     fn test_deserialize(
         #[case] input: String,
         #[case] expected: Username,
@@ -113,5 +128,19 @@ mod tests {
 
         assert_eq!(String::new(), username.name);
         assert_eq!(Vec::<String>::new(), username.parts);
+        assert_eq!(None, username.disambiguation);
+    }
+
+    #[rstest]
+    #[case("Thomas Martin #1", "Thomas Martin #2")]
+    #[case("Thomas Martin #1", "Thomas Martin")]
+    fn test_homonyms_not_equal(
+        #[case] left: &str,
+        #[case] right: &str,
+    ) {
+        let left: Username = left.parse().unwrap();
+        let right: Username = right.parse().unwrap();
+
+        assert!(left != right);
     }
 }
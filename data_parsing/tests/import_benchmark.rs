@@ -0,0 +1,54 @@
+#[cfg(not(debug_assertions))]
+mod import_benchmark {
+    use std::env;
+    use std::path::PathBuf;
+    use std::time::{Duration, Instant};
+
+    use data_parsing::{Database, DivisionAliases, ImportReport, NameDisambiguation};
+    use repository::{Repository, TestDatabase, WriteOnlyRepository};
+    use types::prelude::MeetDto;
+
+    const ENTRIES_ROOT: &str = "/tmp/opl-data/meet-data";
+    const BATCH_SIZES: [usize; 4] = [1, 100, WriteOnlyRepository::DEFAULT_BATCH_SIZE, 5_000];
+
+    /// Inserts every meet into a database of its own, dropped afterwards
+    async fn insert(database: &Database, batch_size: usize) -> Duration {
+        let meets: Vec<MeetDto> = database.iter().cloned().map(MeetDto::from).collect();
+        let test_database: TestDatabase = TestDatabase::create().await.unwrap();
+        let mut repository: WriteOnlyRepository = Repository::write_only_at(&test_database.url()).with_batch_size(batch_size);
+        repository.connect().await.unwrap();
+        repository.apply_migrations().await.unwrap();
+
+        let now: Instant = Instant::now();
+        repository.upsert_meets(meets).await.unwrap();
+        let elapsed: Duration = now.elapsed();
+
+        repository.disconnect().await.unwrap();
+
+        elapsed
+    }
+
+    /// `cargo test --release --test import_benchmark -- --ignored --nocapture`, the data is
+    /// read from `IMPORT_BENCHMARK_DATA`, opl-data by default. The databases are created on the
+    /// server of `TEST_DATABASE_URL`
+    #[tokio::test]
+    #[ignore = "needs a Postgres server, a database is created on it for each batch size"]
+    async fn bench_import() {
+        let path: PathBuf = env::var("IMPORT_BENCHMARK_DATA").map_or_else(|_| PathBuf::from(ENTRIES_ROOT), PathBuf::from);
+        assert!(path.is_dir(), "execute`git clone https://gitlab.com/openpowerlifting/opl-data /tmp/opl-data`");
+
+        let (database, report): (Database, ImportReport) = Database::from_directory(&path, &DivisionAliases::default(), &NameDisambiguation::default()).unwrap();
+
+        for batch_size in BATCH_SIZES {
+            let elapsed: Duration = insert(&database, batch_size).await;
+            #[allow(clippy::cast_precision_loss)]
+            let throughput: f64 = report.imported_entries as f64 / elapsed.as_secs_f64();
+
+            println!(
+                "batch size {batch_size}: {} entries in {}ms, {throughput:.0} rows/s",
+                report.imported_entries,
+                elapsed.as_millis(),
+            );
+        }
+    }
+}
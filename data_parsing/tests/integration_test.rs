@@ -1,11 +1,29 @@
 #[cfg(not(debug_assertions))]
 mod integration_test {
-    use std::path::PathBuf;
+    use rayon::{ThreadPool, ThreadPoolBuilder};
+    use std::collections::BTreeMap;
+    use std::path::{Path, PathBuf};
     use std::time::{Duration, Instant};
 
-    use data_parsing::Database;
+    use data_parsing::{Database, DivisionAliases, ImportReport, NameDisambiguation};
 
     const ENTRIES_ROOT: &str = "/tmp/opl-data/meet-data";
+    /// Share of the meets which may be skipped, opl-data keeps adding values the parsing
+    /// doesn't know yet
+    const MAX_SKIPPED_PERCENT: usize = 1;
+
+    fn load_data(path: &Path, threads: usize) -> (Database, ImportReport, Duration) {
+        let pool: ThreadPool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+
+        let now: Instant = Instant::now();
+        let (database, report) = pool.install(|| Database::from_directory(path, &DivisionAliases::default(), &NameDisambiguation::default())).unwrap();
+        let elapsed: Duration = now.elapsed();
+
+        (database, report, elapsed)
+    }
 
     #[test]
     #[cfg(not(debug_assertions))]
@@ -14,10 +32,34 @@ mod integration_test {
         assert!(path.exists(), "execute`git clone https://gitlab.com/openpowerlifting/opl-data /tmp/opl-data`");
         assert!(path.is_dir());
 
-        let now: Instant = Instant::now();
-        Database::from_directory(&path.to_path_buf()).unwrap();
-        let elapsed: Duration = now.elapsed();
+        let (sequential_database, sequential_report, sequential_elapsed) = load_data(&path, 1);
+        let (database, report, elapsed) = load_data(&path, rayon::current_num_threads());
+        let speedup: f64 = sequential_elapsed.as_secs_f64() / elapsed.as_secs_f64();
+
+        println!(
+            "parsing: {}ms on 1 thread, {}ms on {} threads, speedup x{speedup:.2}",
+            sequential_elapsed.as_millis(),
+            elapsed.as_millis(),
+            rayon::current_num_threads(),
+        );
+
+        let mut categories: BTreeMap<(Option<&str>, &str), usize> = BTreeMap::new();
+        for skipped in &report.skipped {
+            *categories.entry((skipped.column.as_deref(), skipped.error.as_str())).or_default() += 1;
+        }
+        for ((column, error), count) in &categories {
+            println!("skipped {count} meets, column {column:?}: {error}");
+        }
 
+        assert!(report.imported_meets > 0);
+        assert!(
+            report.skipped.len() * 100 <= (report.imported_meets + report.skipped.len()) * MAX_SKIPPED_PERCENT,
+            "{} meets skipped for {} imported",
+            report.skipped.len(),
+            report.imported_meets,
+        );
+        assert_eq!(sequential_report, report);
+        assert!(sequential_database == database, "parsing should not depend on the number of threads");
         assert!(elapsed.as_secs() < 10, "parsing too long: {}ms", elapsed.as_millis());
     }
 }
@@ -1,7 +1,8 @@
 use maud::{html, Markup, DOCTYPE};
 use strum::IntoEnumIterator;
 
-use types::filters::{DivisionFilterDto, EquipmentFilterDto, FederationFilterDto, SexFilterDto};
+use types::filters::{AgeClassFilterDto, BirthYearClassFilterDto, CountryFilterDto, DivisionFilterDto, EquipmentFilterDto, EventFilterDto, FederationFilterDto, MatchModeFilterDto, SexFilterDto, TestedFilterDto, WeightClassFilterDto};
+use types::prelude::{CountryDto, FederationDto};
 
 pub mod powerlifters;
 
@@ -12,6 +13,8 @@ fn css() -> Markup {
                 table { border: 1px solid black; }
                 th { border: 1px solid black; }
                 td { border: 1px solid black; }
+                .low-confidence { background-color: #fff3cd; }
+                .error { color: #842029; background-color: #f8d7da; padding: 0.5em; }
 
                 html, body {
                     min-height: 100vh;
@@ -69,6 +72,8 @@ fn head() -> Markup {
     html! {
         head {
             script src="https://unpkg.com/htmx.org" { }
+            // The error responses carry a fragment explaining the error, htmx swaps them too
+            meta name="htmx-config" content=r#"{"responseHandling": [{"code": "204", "swap": false}, {"code": "[2345]..", "swap": true}]}"#;
             title { "Powerlifting API" }
             (css())
         }
@@ -97,12 +102,12 @@ fn footer() -> Markup {
     }
 }
 
-fn body() -> Markup {
+fn body(federations: Vec<FederationDto>) -> Markup {
     html! {
         body {
             div class="container" {
                 main class="content" {
-                    (input_div())
+                    (input_div(federations))
                     (result_div())
                 }
 
@@ -112,58 +117,161 @@ fn body() -> Markup {
     }
 }
 
-fn input_div() -> Markup {
+fn input_div(federations: Vec<FederationDto>) -> Markup {
     html! {
         div {
             form hx-post="/powerlifters" hx-target="#result" {
                 div {
-                    select id="federation_choice" name="federation_choice" {
-                        @for value in FederationFilterDto::iter() {
+                    select id="federation_choice" name="federation_choice" multiple {
+                        option value=(FederationFilterDto::Any) selected { (FederationFilterDto::Any) }
+                        @for value in federations.into_iter().map(FederationFilterDto::Federation) {
                             option value=(value) { (value) }
                         }
                     }
 
-                    select id="equipment_choice" name="equipment_choice" {
+                    select id="equipment_choice" name="equipment_choice" multiple {
                         @for value in EquipmentFilterDto::iter() {
-                            option value=(value) { (value) }
+                            option value=(value) selected[value == EquipmentFilterDto::Raw] { (value) }
                         }
                     }
 
-                    select id="sex_choice" name="sex_choice" {
+                    select id="sex_choice" name="sex_choice" multiple {
                         @for value in SexFilterDto::iter() {
-                            option value=(value) { (value) }
+                            option value=(value) selected[value == SexFilterDto::Any] { (value) }
                         }
                     }
 
-                    select id="division_choice" name="division_choice" {
+                    select id="division_choice" name="division_choice" multiple {
                         @for value in DivisionFilterDto::iter() {
+                            option value=(value) selected[value == DivisionFilterDto::Any] { (value) }
+                        }
+                    }
+
+                    select id="event_choice" name="event_choice" {
+                        @for value in EventFilterDto::iter() {
+                            option value=(value) { (value) }
+                        }
+                    }
+
+                    select id="tested_choice" name="tested_choice" {
+                        @for value in TestedFilterDto::iter() {
                             option value=(value) { (value) }
                         }
                     }
                 }
 
+                div {
+                    label for="weight_class_choice" { "Class:" }
+                    // The classes depend on the federation and the sex, they are reloaded when
+                    // one of them changes
+                    select id="weight_class_choice" name="weight_class_choice"
+                        hx-get="/weight_classes"
+                        hx-trigger="change from:#federation_choice, change from:#sex_choice"
+                        hx-include="#federation_choice, #sex_choice"
+                        hx-target="this" {
+                        (weight_class_options(&[], &[]))
+                    }
+
+                    input type="checkbox" id="rank_by_bodyweight" name="rank_by_bodyweight" value="true";
+                    label for="rank_by_bodyweight" { "Class of the bodyweight" }
+                }
+
+                div {
+                    label for="age_class_choice" { "Age:" }
+                    select id="age_class_choice" name="age_class_choice" {
+                        @for value in AgeClassFilterDto::iter() {
+                            option value=(value) { (value) }
+                        }
+                    }
+
+                    label for="birth_year_class_choice" { "Birth year class:" }
+                    select id="birth_year_class_choice" name="birth_year_class_choice" {
+                        @for value in BirthYearClassFilterDto::iter() {
+                            option value=(value) { (value) }
+                        }
+                    }
+                }
+
+                div {
+                    label for="meet_country_choice" { "Meet country:" }
+                    (country_select("meet_country_choice"))
+
+                    label for="lifter_country_choice" { "Lifter country:" }
+                    (country_select("lifter_country_choice"))
+                }
+
+                div {
+                    label for="date_from" { "From:" }
+                    input type="date" id="date_from" name="date_from";
+
+                    label for="date_to" { "To:" }
+                    input type="date" id="date_to" name="date_to";
+
+                    input type="checkbox" id="exclude_disqualified" name="exclude_disqualified" value="true" checked;
+                    label for="exclude_disqualified" { "Exclude DQ/DD/NS" }
+                }
+
                 label for="powerlifters" { "Powerlifters:" }
                 br;
 
                 textarea name="powerlifters" id="powerlifters" rows="5" cols="40" { }
                 br;
 
+                label for="match_mode_choice" { "Name matching:" }
+                select id="match_mode_choice" name="match_mode_choice" {
+                    @for value in MatchModeFilterDto::iter() {
+                        option value=(value) { (value) }
+                    }
+                }
+                br;
+
                 button type="submit" { "Send" }
             }
         }
     }
 }
 
+fn country_select(name: &str) -> Markup {
+    html! {
+        select id=(name) name=(name) {
+            @for value in std::iter::once(CountryFilterDto::Any).chain(CountryDto::iter().map(CountryFilterDto::Country)) {
+                option value=(value.value()) { (value) }
+            }
+        }
+    }
+}
+
+/// Options of the weight class select, grouped by set of classes
+pub fn weight_class_options(federations: &[FederationFilterDto], sexes: &[SexFilterDto]) -> Markup {
+    html! {
+        option value=(WeightClassFilterDto::Any.value()) { (WeightClassFilterDto::Any) }
+        @for (set, sex, classes) in WeightClassFilterDto::options(federations, sexes) {
+            optgroup label=(format!("{set} {sex}")) {
+                @for value in classes {
+                    option value=(value.value()) { (value) }
+                }
+            }
+        }
+    }
+}
+
+/// Message displayed in place of the result when a request fails
+pub fn error_fragment(message: &str) -> Markup {
+    html! {
+        div class="error" role="alert" { (message) }
+    }
+}
+
 fn result_div() -> Markup {
     html! {
         div id="result" { }
     }
 }
 
-pub fn root_page() -> Markup {
+pub fn root_page(federations: Vec<FederationDto>) -> Markup {
     html! {
         (DOCTYPE)
         (head())
-        (body())
+        (body(federations))
     }
 }
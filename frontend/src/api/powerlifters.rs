@@ -1,13 +1,18 @@
 use maud::{html, Markup};
 use types::prelude::*;
 
-pub const POWERLIFTER_TABLE_HEADERS: [&str; 12] = [
+pub const POWERLIFTER_TABLE_HEADERS: [&str; 17] = [
     "Rank", 
+    "Place", 
     "Lifter", 
+    "Match", 
+    "Country", 
     "Federation", 
     "Division", 
     "Sex", 
+    "Age", 
     "Equipment", 
+    "Event", 
     "Class", 
     "Weight", 
     "Squat", 
@@ -27,13 +32,22 @@ pub fn build_table(data: Vec<ExportRow>) -> Markup {
             }
 
             @for row in data {
-                tr {
+                tr class=[row.low_confidence.then_some("low-confidence")] {
                     td { (row.rank) }
+                    td { (row.place) }
                     td { (row.name) }
-                    td { "FFForce" }
+                    @if row.low_confidence {
+                        td title="Low confidence match, please check the lifter" { (row.match_score) " ?" }
+                    } @else {
+                        td { (row.match_score) }
+                    }
+                    td { (row.country) }
+                    td { (row.federation) }
                     td { (row.division) }
                     td { (row.sex) }
+                    td { (row.age) }
                     td { (row.equipment) }
+                    td { (row.event) }
                     td { (row.weight_class) }
                     td { (row.bodyweight) }
                     td { (row.best_squat) }
@@ -46,3 +60,31 @@ pub fn build_table(data: Vec<ExportRow>) -> Markup {
         }
     }
 }
+
+/// Ranking of the matched lifters, followed by the requested names which need attention
+pub fn build_result(result: ExportResult) -> Markup {
+    html! {
+        (build_table(result.matched))
+
+        @if !result.unmatched.is_empty() {
+            section class="unmatched" {
+                h3 { "Not found (" (result.unmatched.len()) ")" }
+                ul {
+                    @for name in result.unmatched {
+                        li { (name) }
+                    }
+                }
+            }
+        }
+
+        @if !result.ambiguous.is_empty() {
+            section class="ambiguous" {
+                h3 { "Ambiguous (" (result.ambiguous.len()) ")" }
+                @for ambiguous in result.ambiguous {
+                    h4 { (ambiguous.name) ": " (ambiguous.candidates.len()) " lifters" }
+                    (build_table(ambiguous.candidates))
+                }
+            }
+        }
+    }
+}
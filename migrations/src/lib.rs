@@ -1,6 +1,22 @@
 pub use sea_orm_migration::prelude::*;
+pub use sea_orm_migration::MigrationStatus;
 
 mod m20251113_195907_create_tables;
+mod m20261018_090000_add_meet_date;
+mod m20261018_093000_add_entry_place;
+mod m20261018_100000_add_meet_path_and_hash;
+mod m20261018_110000_add_entry_event;
+mod m20261018_120000_add_entry_age;
+mod m20261018_130000_add_entry_tested;
+mod m20261018_140000_create_federations;
+mod m20261018_150000_add_countries;
+mod m20261018_160000_add_entry_division_raw;
+mod m20261018_170000_create_lifters;
+mod m20261018_180000_add_entry_name_normalized;
+mod m20261018_190000_enable_pg_trgm;
+mod m20261018_200000_add_entry_weight_class_over;
+mod m20261018_210000_add_entry_name_trigram_index;
+mod sqlite_schema;
 
 pub struct Migrator;
 
@@ -8,7 +24,21 @@ pub struct Migrator;
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
         vec![
-            Box::new(m20251113_195907_create_tables::Migration)
+            Box::new(m20251113_195907_create_tables::Migration),
+            Box::new(m20261018_090000_add_meet_date::Migration),
+            Box::new(m20261018_093000_add_entry_place::Migration),
+            Box::new(m20261018_100000_add_meet_path_and_hash::Migration),
+            Box::new(m20261018_110000_add_entry_event::Migration),
+            Box::new(m20261018_120000_add_entry_age::Migration),
+            Box::new(m20261018_130000_add_entry_tested::Migration),
+            Box::new(m20261018_140000_create_federations::Migration),
+            Box::new(m20261018_150000_add_countries::Migration),
+            Box::new(m20261018_160000_add_entry_division_raw::Migration),
+            Box::new(m20261018_170000_create_lifters::Migration),
+            Box::new(m20261018_180000_add_entry_name_normalized::Migration),
+            Box::new(m20261018_190000_enable_pg_trgm::Migration),
+            Box::new(m20261018_200000_add_entry_weight_class_over::Migration),
+            Box::new(m20261018_210000_add_entry_name_trigram_index::Migration),
         ]
     }
 }
@@ -2,6 +2,8 @@ use sea_orm_migration::prelude::extension::postgres::Type;
 use sea_orm_migration::prelude::sea_orm::{DeriveIden, DeriveMigrationName};
 use sea_orm_migration::prelude::{async_trait, ColumnDef, DbErr, ForeignKey, ForeignKeyAction, MigrationTrait, SchemaManager, Table};
 
+use crate::sqlite_schema;
+
 const PRECISION: u32 = 8;
 const SCALE: u32 = 4;
 
@@ -11,10 +13,14 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return sqlite_schema::create(manager).await;
+        }
+
         manager
             .create_type(
                 Type::create()
-                    .as_enum(Federation::Federation)
+                    .as_enum(Federation::Table)
                     .values([
                         Federation::Ffforce,
                         Federation::Epf,
@@ -29,7 +35,7 @@ impl MigrationTrait for Migration {
         manager
             .create_type(
                 Type::create()
-                    .as_enum(Country::Country)
+                    .as_enum(Country::Table)
                     .values([
                         Country::France,
                         Country::Other,
@@ -41,7 +47,7 @@ impl MigrationTrait for Migration {
         manager
             .create_type(
                 Type::create()
-                    .as_enum(Division::Division)
+                    .as_enum(Division::Table)
                     .values([
                         Division::Open,
                         Division::G,
@@ -63,7 +69,7 @@ impl MigrationTrait for Migration {
         manager
             .create_type(
                 Type::create()
-                    .as_enum(Equipment::Equipment)
+                    .as_enum(Equipment::Table)
                     .values([
                         Equipment::Raw,
                         Equipment::Wraps,
@@ -81,7 +87,7 @@ impl MigrationTrait for Migration {
         manager
             .create_type(
                 Type::create()
-                    .as_enum(Sex::Sex)
+                    .as_enum(Sex::Table)
                     .values([
                         Sex::M,
                         Sex::F,
@@ -93,12 +99,12 @@ impl MigrationTrait for Migration {
         manager
             .create_table(
                 Table::create()
-                    .table(Meets::Meets)
+                    .table(Meets::Table)
                     .if_not_exists()
                     .col(ColumnDef::new(Meets::Id).integer().unique_key().primary_key().auto_increment().not_null())
                     .col(ColumnDef::new(Meets::Name).string_len(256).not_null())
-                    .col(ColumnDef::new(Meets::Federation).custom(Federation::Federation).not_null())
-                    .col(ColumnDef::new(Meets::Country).custom(Country::Country).not_null())
+                    .col(ColumnDef::new(Meets::Federation).custom(Federation::Table).not_null())
+                    .col(ColumnDef::new(Meets::Country).custom(Country::Table).not_null())
                     .col(ColumnDef::new(Meets::State).string_len(256).not_null())
                     .col(ColumnDef::new(Meets::Town).string_len(256).not_null())
                     .to_owned()
@@ -108,14 +114,14 @@ impl MigrationTrait for Migration {
         manager
             .create_table(
                 Table::create()
-                    .table(Entries::Entries)
+                    .table(Entries::Table)
                     .if_not_exists()
                     .col(ColumnDef::new(Entries::Id).integer().unique_key().primary_key().auto_increment().not_null())
                     .col(ColumnDef::new(Entries::Meetid).integer().not_null())
                     .col(ColumnDef::new(Entries::Name).string_len(256).not_null())
-                    .col(ColumnDef::new(Entries::Division).custom(Division::Division).not_null())
-                    .col(ColumnDef::new(Entries::Equipment).custom(Equipment::Equipment).not_null())
-                    .col(ColumnDef::new(Entries::Sex).custom(Sex::Sex).not_null())
+                    .col(ColumnDef::new(Entries::Division).custom(Division::Table).not_null())
+                    .col(ColumnDef::new(Entries::Equipment).custom(Equipment::Table).not_null())
+                    .col(ColumnDef::new(Entries::Sex).custom(Sex::Table).not_null())
                     .col(ColumnDef::new(Entries::Bodyweight).decimal_len(PRECISION, SCALE).not_null())
                     .col(ColumnDef::new(Entries::Weightclass).decimal_len(PRECISION, SCALE))
                     .col(ColumnDef::new(Entries::Squat1).decimal_len(PRECISION, SCALE))
@@ -136,8 +142,8 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(Entries::Total).decimal_len(PRECISION, SCALE))
                     .foreign_key(
                         ForeignKey::create()
-                            .from(Entries::Entries, Entries::Meetid)
-                            .to(Meets::Meets, Meets::Id)
+                            .from(Entries::Table, Entries::Meetid)
+                            .to(Meets::Table, Meets::Id)
                             .on_delete(ForeignKeyAction::Cascade)
                             .on_update(ForeignKeyAction::Cascade)
                     )
@@ -149,10 +155,14 @@ impl MigrationTrait for Migration {
     }
 
     async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return sqlite_schema::drop(manager).await;
+        }
+
         manager
             .drop_table(
                 Table::drop()
-                    .table(Entries::Entries)
+                    .table(Entries::Table)
                     .if_exists()
                     .to_owned()
             )
@@ -161,7 +171,7 @@ impl MigrationTrait for Migration {
         manager
             .drop_table(
                 Table::drop()
-                    .table(Meets::Meets)
+                    .table(Meets::Table)
                     .if_exists()
                     .to_owned()
             )
@@ -170,7 +180,7 @@ impl MigrationTrait for Migration {
         manager
             .drop_type(
                 Type::drop()
-                .name(Sex::Sex)
+                .name(Sex::Table)
                 .if_exists()
                 .to_owned()
             )
@@ -179,7 +189,7 @@ impl MigrationTrait for Migration {
         manager
             .drop_type(
                 Type::drop()
-                .name(Equipment::Equipment)
+                .name(Equipment::Table)
                 .if_exists()
                 .to_owned()
             )
@@ -188,7 +198,7 @@ impl MigrationTrait for Migration {
         manager
             .drop_type(
                 Type::drop()
-                    .name(Division::Division)
+                    .name(Division::Table)
                     .if_exists()
                     .to_owned()
             )
@@ -197,7 +207,7 @@ impl MigrationTrait for Migration {
         manager
             .drop_type(
                 Type::drop()
-                    .name(Country::Country)
+                    .name(Country::Table)
                     .if_exists()
                     .to_owned()
             )
@@ -206,7 +216,7 @@ impl MigrationTrait for Migration {
         manager
             .drop_type(
                 Type::drop()
-                    .name(Federation::Federation)
+                    .name(Federation::Table)
                     .if_exists()
                     .to_owned()
             )
@@ -218,7 +228,7 @@ impl MigrationTrait for Migration {
 
 #[derive(DeriveIden)]
 enum Federation {
-    Federation,
+    Table,
 
     Ffforce,
     Epf,
@@ -229,7 +239,7 @@ enum Federation {
 
 #[derive(DeriveIden)]
 enum Country  {
-    Country,
+    Table,
 
     France,
     Other,
@@ -237,7 +247,7 @@ enum Country  {
 
 #[derive(DeriveIden)]
 enum Division {
-    Division,
+    Table,
 
     Open,
     G,
@@ -255,7 +265,7 @@ enum Division {
 
 #[derive(DeriveIden)]
 enum Equipment {
-    Equipment,
+    Table,
 
     Raw,
     Wraps,
@@ -269,7 +279,7 @@ enum Equipment {
 
 #[derive(DeriveIden)]
 enum Sex {
-    Sex,
+    Table,
 
     M,
     F,
@@ -278,7 +288,7 @@ enum Sex {
 #[derive(DeriveIden)]
 #[sea_orm(enum_name = "entries")]
 enum Entries {
-    Entries,
+    Table,
 
     Id,
     #[sea_orm(iden = "meet_id")]
@@ -313,7 +323,7 @@ enum Entries {
 
 #[derive(DeriveIden)]
 enum Meets {
-    Meets,
+    Table,
 
     Id,
     Name,
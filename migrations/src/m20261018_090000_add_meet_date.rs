@@ -0,0 +1,51 @@
+use sea_orm_migration::prelude::sea_orm::{DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, ColumnDef, DbErr, MigrationTrait, SchemaManager, Table};
+
+use crate::sqlite_schema;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Meets::Table)
+                    .add_column(ColumnDef::new(Meets::Date).date())
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Meets::Table)
+                    .drop_column(Meets::Date)
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Meets {
+    Table,
+
+    Date,
+}
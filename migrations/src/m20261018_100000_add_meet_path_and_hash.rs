@@ -0,0 +1,94 @@
+use sea_orm_migration::prelude::sea_orm::{DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, ColumnDef, DbErr, Index, MigrationTrait, SchemaManager, Table};
+
+use crate::sqlite_schema;
+
+const PATH_LENGTH: u32 = 512;
+const HASH_LENGTH: u32 = 64;
+const PATH_INDEX: &str = "idx_meets_path";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Meets::Table)
+                    .add_column(ColumnDef::new(Meets::Path).string_len(PATH_LENGTH))
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Meets::Table)
+                    .add_column(ColumnDef::new(Meets::Hash).string_len(HASH_LENGTH))
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(PATH_INDEX)
+                    .table(Meets::Table)
+                    .col(Meets::Path)
+                    .unique()
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(PATH_INDEX)
+                    .table(Meets::Table)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Meets::Table)
+                    .drop_column(Meets::Hash)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Meets::Table)
+                    .drop_column(Meets::Path)
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Meets {
+    Table,
+
+    Path,
+    Hash,
+}
@@ -0,0 +1,118 @@
+use sea_orm_migration::prelude::extension::postgres::Type;
+use sea_orm_migration::prelude::sea_orm::{ConnectionTrait, DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, ColumnDef, DbErr, Expr, MigrationTrait, Query, SchemaManager, Table};
+
+use crate::sqlite_schema;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Event::Table)
+                    .values([
+                        Event::Sbd,
+                        Event::Bd,
+                        Event::Sd,
+                        Event::Sb,
+                        Event::S,
+                        Event::B,
+                        Event::D,
+                    ])
+                    .to_owned()
+            )
+            .await?;
+
+        // The default only fills the entries imported before, their event is unknown until the
+        // meets are imported again
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .add_column(
+                        ColumnDef::new(Entries::Event)
+                            .custom(Event::Table)
+                            .not_null()
+                            .default(Expr::val("sbd").as_enum(Event::Table))
+                    )
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE entries ALTER COLUMN event DROP DEFAULT")
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Meets::Table)
+                    .value(Meets::Hash, Expr::cust("NULL"))
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .drop_column(Entries::Event)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Event::Table)
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Meets {
+    Table,
+
+    Hash,
+}
+
+#[derive(DeriveIden)]
+enum Entries {
+    Table,
+
+    Event,
+}
+
+#[derive(DeriveIden)]
+enum Event {
+    Table,
+
+    Sbd,
+    Bd,
+    Sd,
+    Sb,
+    S,
+    B,
+    D,
+}
@@ -0,0 +1,212 @@
+use sea_orm_migration::prelude::extension::postgres::Type;
+use sea_orm_migration::prelude::sea_orm::{DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, ColumnDef, DbErr, Expr, MigrationTrait, Query, SchemaManager, Table};
+
+use crate::sqlite_schema;
+
+const AGE_PRECISION: u32 = 4;
+const AGE_SCALE: u32 = 1;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(AgeClass::Table)
+                    .values([
+                        AgeClass::Age5To12,
+                        AgeClass::Age13To15,
+                        AgeClass::Age16To17,
+                        AgeClass::Age18To19,
+                        AgeClass::Age20To23,
+                        AgeClass::Age24To34,
+                        AgeClass::Age35To39,
+                        AgeClass::Age40To44,
+                        AgeClass::Age45To49,
+                        AgeClass::Age50To54,
+                        AgeClass::Age55To59,
+                        AgeClass::Age60To64,
+                        AgeClass::Age65To69,
+                        AgeClass::Age70To74,
+                        AgeClass::Age75To79,
+                        AgeClass::Age80To999,
+                    ])
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(BirthYearClass::Table)
+                    .values([
+                        BirthYearClass::Age14To18,
+                        BirthYearClass::Age19To23,
+                        BirthYearClass::Age24To39,
+                        BirthYearClass::Age40To49,
+                        BirthYearClass::Age50To59,
+                        BirthYearClass::Age60To69,
+                        BirthYearClass::Age70To999,
+                    ])
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .add_column(ColumnDef::new(Entries::Age).decimal_len(AGE_PRECISION, AGE_SCALE))
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .add_column(ColumnDef::new(Entries::AgeClass).custom(AgeClass::Table))
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .add_column(ColumnDef::new(Entries::BirthYearClass).custom(BirthYearClass::Table))
+                    .to_owned()
+            )
+            .await?;
+
+        // The meets imported before miss the new columns, they are imported again by the next
+        // import
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Meets::Table)
+                    .value(Meets::Hash, Expr::cust("NULL"))
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        for column in [Entries::Age, Entries::AgeClass, Entries::BirthYearClass] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Entries::Table)
+                        .drop_column(column)
+                        .to_owned()
+                )
+                .await?;
+        }
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(AgeClass::Table)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(BirthYearClass::Table)
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Meets {
+    Table,
+
+    Hash,
+}
+
+#[derive(DeriveIden)]
+enum Entries {
+    Table,
+
+    Age,
+    AgeClass,
+    BirthYearClass,
+}
+
+#[derive(DeriveIden)]
+enum AgeClass {
+    Table,
+
+    #[sea_orm(iden = "5-12")]
+    Age5To12,
+    #[sea_orm(iden = "13-15")]
+    Age13To15,
+    #[sea_orm(iden = "16-17")]
+    Age16To17,
+    #[sea_orm(iden = "18-19")]
+    Age18To19,
+    #[sea_orm(iden = "20-23")]
+    Age20To23,
+    #[sea_orm(iden = "24-34")]
+    Age24To34,
+    #[sea_orm(iden = "35-39")]
+    Age35To39,
+    #[sea_orm(iden = "40-44")]
+    Age40To44,
+    #[sea_orm(iden = "45-49")]
+    Age45To49,
+    #[sea_orm(iden = "50-54")]
+    Age50To54,
+    #[sea_orm(iden = "55-59")]
+    Age55To59,
+    #[sea_orm(iden = "60-64")]
+    Age60To64,
+    #[sea_orm(iden = "65-69")]
+    Age65To69,
+    #[sea_orm(iden = "70-74")]
+    Age70To74,
+    #[sea_orm(iden = "75-79")]
+    Age75To79,
+    #[sea_orm(iden = "80-999")]
+    Age80To999,
+}
+
+#[derive(DeriveIden)]
+enum BirthYearClass {
+    Table,
+
+    #[sea_orm(iden = "14-18")]
+    Age14To18,
+    #[sea_orm(iden = "19-23")]
+    Age19To23,
+    #[sea_orm(iden = "24-39")]
+    Age24To39,
+    #[sea_orm(iden = "40-49")]
+    Age40To49,
+    #[sea_orm(iden = "50-59")]
+    Age50To59,
+    #[sea_orm(iden = "60-69")]
+    Age60To69,
+    #[sea_orm(iden = "70-999")]
+    Age70To999,
+}
@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::sea_orm::{DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, ColumnDef, DbErr, Expr, MigrationTrait, Query, SchemaManager, Table};
+
+use crate::sqlite_schema;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .add_column(ColumnDef::new(Entries::Tested).boolean().not_null().default(false))
+                    .to_owned()
+            )
+            .await?;
+
+        // The meets imported before are all marked as untested until the next import
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Meets::Table)
+                    .value(Meets::Hash, Expr::cust("NULL"))
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .drop_column(Entries::Tested)
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Meets {
+    Table,
+
+    Hash,
+}
+
+#[derive(DeriveIden)]
+enum Entries {
+    Table,
+
+    Tested,
+}
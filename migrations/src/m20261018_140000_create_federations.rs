@@ -0,0 +1,194 @@
+use sea_orm_migration::prelude::extension::postgres::Type;
+use sea_orm_migration::prelude::sea_orm::{ConnectionTrait, DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, Alias, ColumnDef, DbErr, Expr, ForeignKey, ForeignKeyAction, MigrationTrait, Query, SchemaManager, Table};
+
+use crate::sqlite_schema;
+
+const CODE_LENGTH: u32 = 32;
+const FOREIGN_KEY_NAME: &str = "fk_meets_federation";
+
+/// Codes of the federations known by the `federation` type
+const FEDERATIONS: [(&str, &str); 5] = [
+    ("ffforce", "FFForce"),
+    ("epf", "EPF"),
+    ("ipf", "IPF"),
+    ("ffhmfac", "FFHMFAC"),
+    ("other", "Other"),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Federations::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Federations::Id).integer().primary_key().auto_increment().not_null())
+                    .col(ColumnDef::new(Federations::Code).string_len(CODE_LENGTH).unique_key().not_null())
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Meets::Table)
+                    .modify_column(ColumnDef::new(Meets::Federation).string_len(CODE_LENGTH).not_null())
+                    .to_owned()
+            )
+            .await?;
+
+        for (value, code) in FEDERATIONS {
+            manager
+                .exec_stmt(
+                    Query::update()
+                        .table(Meets::Table)
+                        .value(Meets::Federation, code)
+                        .and_where(Expr::col(Meets::Federation).eq(value))
+                        .to_owned()
+                )
+                .await?;
+        }
+
+        manager
+            .exec_stmt(
+                Query::insert()
+                    .into_table(Federations::Table)
+                    .columns([Federations::Code])
+                    .select_from(
+                        Query::select()
+                            .distinct()
+                            .column(Meets::Federation)
+                            .from(Meets::Table)
+                            .to_owned()
+                    )
+                    .map_err(|e| DbErr::Migration(e.to_string()))?
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FOREIGN_KEY_NAME)
+                    .from(Meets::Table, Meets::Federation)
+                    .to(Federations::Table, Federations::Code)
+                    .on_delete(ForeignKeyAction::Restrict)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Federation::Table)
+                    .to_owned()
+            )
+            .await?;
+
+        // The meets imported before only kept the five codes above, the next import writes the
+        // code of every meet
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Meets::Table)
+                    .value(Meets::Hash, Expr::cust("NULL"))
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FOREIGN_KEY_NAME)
+                    .table(Meets::Table)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(Federations::Table)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Federation::Table)
+                    .values(FEDERATIONS.map(|(value, _)| Alias::new(value)))
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Meets::Table)
+                    .value(Meets::Federation, "other")
+                    .and_where(Expr::col(Meets::Federation).is_not_in(FEDERATIONS.map(|(_, code)| code)))
+                    .to_owned()
+            )
+            .await?;
+
+        for (value, code) in FEDERATIONS {
+            manager
+                .exec_stmt(
+                    Query::update()
+                        .table(Meets::Table)
+                        .value(Meets::Federation, value)
+                        .and_where(Expr::col(Meets::Federation).eq(code))
+                        .to_owned()
+                )
+                .await?;
+        }
+
+        // Postgres needs an explicit cast to convert a string column to an enum
+        manager
+            .get_connection()
+            .execute_unprepared("ALTER TABLE meets ALTER COLUMN federation TYPE federation USING federation::federation")
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Federations {
+    Table,
+
+    Id,
+    Code,
+}
+
+#[derive(DeriveIden)]
+enum Meets {
+    Table,
+
+    Federation,
+    Hash,
+}
+
+#[derive(DeriveIden)]
+enum Federation {
+    Table,
+}
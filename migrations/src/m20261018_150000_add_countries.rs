@@ -0,0 +1,142 @@
+use sea_orm_migration::prelude::extension::postgres::Type;
+use sea_orm_migration::prelude::sea_orm::{ConnectionTrait, DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, Alias, ColumnDef, DbErr, Expr, MigrationTrait, Query, SchemaManager, Table};
+
+use crate::sqlite_schema;
+
+const ISO_LENGTH: u32 = 8;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Meets::Table)
+                    .modify_column(ColumnDef::new(Meets::Country).string_len(ISO_LENGTH).null())
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Meets::Table)
+                    .value(Meets::Country, "FR")
+                    .and_where(Expr::col(Meets::Country).eq("france"))
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Meets::Table)
+                    .value(Meets::Country, Expr::cust("NULL"))
+                    .and_where(Expr::col(Meets::Country).eq("other"))
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .drop_type(
+                Type::drop()
+                    .name(Country::Table)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .add_column(ColumnDef::new(Entries::Country).string_len(ISO_LENGTH))
+                    .to_owned()
+            )
+            .await?;
+
+        // Only french meets had a country before and lifters had none, the next import writes
+        // them
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Meets::Table)
+                    .value(Meets::Hash, Expr::cust("NULL"))
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .drop_column(Entries::Country)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(Country::Table)
+                    .values([Alias::new("france"), Alias::new("other")])
+                    .to_owned()
+            )
+            .await?;
+
+        // Postgres needs an explicit cast to convert a string column to an enum
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TABLE meets ALTER COLUMN country TYPE country \
+                 USING (CASE WHEN country = 'FR' THEN 'france' ELSE 'other' END)::country"
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Meets::Table)
+                    .modify_column(ColumnDef::new(Meets::Country).custom(Country::Table).not_null())
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Meets {
+    Table,
+
+    Country,
+    Hash,
+}
+
+#[derive(DeriveIden)]
+enum Entries {
+    Table,
+
+    Country,
+}
+
+#[derive(DeriveIden)]
+enum Country {
+    Table,
+}
@@ -0,0 +1,131 @@
+use sea_orm_migration::prelude::extension::postgres::Type;
+use sea_orm_migration::prelude::sea_orm::{ConnectionTrait, DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, Alias, ColumnDef, DbErr, Expr, MigrationTrait, Query, SchemaManager, Table};
+
+use crate::sqlite_schema;
+
+const DIVISION_RAW_LENGTH: u32 = 256;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(Division::Table)
+                    .add_value(Division::Unknown)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .add_column(ColumnDef::new(Entries::DivisionRaw).string_len(DIVISION_RAW_LENGTH).not_null().default(""))
+                    .to_owned()
+            )
+            .await?;
+
+        // The meets imported before have no raw division and the meets with unknown divisions
+        // were not imported at all
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Meets::Table)
+                    .value(Meets::Hash, Expr::cust("NULL"))
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .drop_column(Entries::DivisionRaw)
+                    .to_owned()
+            )
+            .await?;
+
+        // The entries of an unknown division couldn't be parsed before, their meets are
+        // imported again and skipped by the next import
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Meets::Table)
+                    .value(Meets::Hash, Expr::cust("NULL"))
+                    .and_where(
+                        Expr::col(Meets::Id).in_subquery(
+                            Query::select()
+                                .column(Entries::MeetId)
+                                .from(Entries::Table)
+                                .and_where(Expr::col(Entries::Division).cast_as(Alias::new("text")).eq("unknown"))
+                                .to_owned()
+                        )
+                    )
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(Entries::Table)
+                    .and_where(Expr::col(Entries::Division).cast_as(Alias::new("text")).eq("unknown"))
+                    .to_owned()
+            )
+            .await?;
+
+        // Postgres can't remove a value from an enum, the type is created again without it
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "ALTER TYPE division RENAME TO division_old;
+                CREATE TYPE division AS ENUM ('open', 'g', 'cadet', 'elite', 'subjuniors', 'juniors', 'seniors', 'masters', 'masters1', 'masters2', 'masters3', 'masters4');
+                ALTER TABLE entries ALTER COLUMN division TYPE division USING division::text::division;
+                DROP TYPE division_old;"
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Meets {
+    Table,
+
+    Id,
+    Hash,
+}
+
+#[derive(DeriveIden)]
+enum Entries {
+    Table,
+
+    MeetId,
+    Division,
+    DivisionRaw,
+}
+
+#[derive(DeriveIden)]
+enum Division {
+    Table,
+
+    Unknown,
+}
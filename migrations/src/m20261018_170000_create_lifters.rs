@@ -0,0 +1,152 @@
+use sea_orm_migration::prelude::sea_orm::{DeriveIden, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, ColumnDef, DbErr, Expr, ForeignKey, ForeignKeyAction, Index, MigrationTrait, Query, SchemaManager, SimpleExpr, Table};
+
+use crate::sqlite_schema;
+
+const NAME_LENGTH: u32 = 256;
+const FOREIGN_KEY_NAME: &str = "fk_entries_lifter";
+const INDEX_NAME: &str = "idx_entries_lifter_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Lifters::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Lifters::Id).integer().primary_key().auto_increment().not_null())
+                    .col(ColumnDef::new(Lifters::Name).string_len(NAME_LENGTH).unique_key().not_null())
+                    .to_owned()
+            )
+            .await?;
+
+        // The names of the entries already contain the disambiguation numbers of opl-data
+        manager
+            .exec_stmt(
+                Query::insert()
+                    .into_table(Lifters::Table)
+                    .columns([Lifters::Name])
+                    .select_from(
+                        Query::select()
+                            .distinct()
+                            .column(Entries::Name)
+                            .from(Entries::Table)
+                            .to_owned()
+                    )
+                    .map_err(|e| DbErr::Migration(e.to_string()))?
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .add_column(ColumnDef::new(Entries::LifterId).integer())
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Entries::Table)
+                    .value(
+                        Entries::LifterId,
+                        SimpleExpr::SubQuery(
+                            None,
+                            Box::new(Query::select()
+                                .column(Lifters::Id)
+                                .from(Lifters::Table)
+                                .and_where(Expr::col((Lifters::Table, Lifters::Name)).equals((Entries::Table, Entries::Name)))
+                                .to_owned()
+                                .into_sub_query_statement())
+                        )
+                    )
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .modify_column(ColumnDef::new(Entries::LifterId).integer().not_null())
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FOREIGN_KEY_NAME)
+                    .from(Entries::Table, Entries::LifterId)
+                    .to(Lifters::Table, Lifters::Id)
+                    .on_delete(ForeignKeyAction::Restrict)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(INDEX_NAME)
+                    .table(Entries::Table)
+                    .col(Entries::LifterId)
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Entries::Table)
+                    .drop_column(Entries::LifterId)
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(Lifters::Table)
+                    .to_owned()
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Lifters {
+    Table,
+
+    Id,
+    Name,
+}
+
+#[derive(DeriveIden)]
+enum Entries {
+    Table,
+
+    Name,
+    LifterId,
+}
@@ -0,0 +1,38 @@
+use sea_orm_migration::prelude::sea_orm::{ConnectionTrait, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, DbErr, MigrationTrait, SchemaManager};
+
+use crate::sqlite_schema;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// The fuzzy search compares the requested words to the names with the trigram similarity of
+/// the `pg_trgm` extension
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .get_connection()
+            .execute_unprepared("CREATE EXTENSION IF NOT EXISTS pg_trgm")
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if sqlite_schema::is_created_at_once(manager) {
+            return Ok(());
+        }
+
+        manager
+            .get_connection()
+            .execute_unprepared("DROP EXTENSION IF EXISTS pg_trgm")
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use sea_orm_migration::prelude::sea_orm::{ConnectionTrait, DbBackend, DeriveMigrationName};
+use sea_orm_migration::prelude::{async_trait, DbErr, MigrationTrait, SchemaManager};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Trigram index of the normalized names, the fuzzy search looks the similar words up in it
+/// rather than comparing every entry. Sqlite has no `pg_trgm`, its fuzzy search scores every
+/// candidate
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "CREATE INDEX IF NOT EXISTS idx_entries_name_normalized_trgm ON entries USING gin (name_normalized gin_trgm_ops)"
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Postgres {
+            return Ok(());
+        }
+
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS idx_entries_name_normalized_trgm")
+            .await?;
+
+        Ok(())
+    }
+}
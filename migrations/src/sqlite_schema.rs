@@ -0,0 +1,254 @@
+use sea_orm_migration::prelude::sea_orm::{DbBackend, DeriveIden};
+use sea_orm_migration::prelude::{ColumnDef, DbErr, ForeignKey, ForeignKeyAction, Index, IntoIden, SchemaManager, Table};
+
+const PRECISION: u32 = 8;
+const SCALE: u32 = 4;
+const AGE_PRECISION: u32 = 4;
+const AGE_SCALE: u32 = 1;
+const NAME_LENGTH: u32 = 256;
+const NAME_NORMALIZED_LENGTH: u32 = 1024;
+const CODE_LENGTH: u32 = 32;
+const ISO_LENGTH: u32 = 8;
+const PLACE_LENGTH: u32 = 8;
+const ENUM_LENGTH: u32 = 32;
+const PATH_LENGTH: u32 = 512;
+const HASH_LENGTH: u32 = 64;
+
+/// Sqlite can't alter the columns nor add the foreign keys of an existing table, and has no
+/// enum types nor `pg_trgm`. A sqlite database never held the data of the older schemas, so
+/// the first migration creates the schema of `m20261018_190000_enable_pg_trgm` at once and the
+/// migrations up to it are skipped, the migrations after it are applied on both backends
+pub(crate) fn is_created_at_once(manager: &SchemaManager) -> bool {
+    manager.get_database_backend() == DbBackend::Sqlite
+}
+
+pub(crate) async fn create(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+    manager
+        .create_table(
+            Table::create()
+                .table(Federations::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(Federations::Id).integer().primary_key().auto_increment().not_null())
+                .col(ColumnDef::new(Federations::Code).string_len(CODE_LENGTH).unique_key().not_null())
+                .to_owned()
+        )
+        .await?;
+
+    manager
+        .create_table(
+            Table::create()
+                .table(Lifters::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(Lifters::Id).integer().primary_key().auto_increment().not_null())
+                .col(ColumnDef::new(Lifters::Name).string_len(NAME_LENGTH).unique_key().not_null())
+                .to_owned()
+        )
+        .await?;
+
+    manager
+        .create_table(
+            Table::create()
+                .table(Meets::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(Meets::Id).integer().primary_key().auto_increment().not_null())
+                .col(ColumnDef::new(Meets::Name).string_len(NAME_LENGTH).not_null())
+                .col(ColumnDef::new(Meets::Federation).string_len(CODE_LENGTH).not_null())
+                .col(ColumnDef::new(Meets::Country).string_len(ISO_LENGTH))
+                .col(ColumnDef::new(Meets::State).string_len(NAME_LENGTH).not_null())
+                .col(ColumnDef::new(Meets::Town).string_len(NAME_LENGTH).not_null())
+                .col(ColumnDef::new(Meets::Date).date())
+                .col(ColumnDef::new(Meets::Path).string_len(PATH_LENGTH))
+                .col(ColumnDef::new(Meets::Hash).string_len(HASH_LENGTH))
+                .foreign_key(
+                    ForeignKey::create()
+                        .name("fk_meets_federation")
+                        .from(Meets::Table, Meets::Federation)
+                        .to(Federations::Table, Federations::Code)
+                        .on_delete(ForeignKeyAction::Restrict)
+                        .on_update(ForeignKeyAction::Cascade)
+                )
+                .to_owned()
+        )
+        .await?;
+
+    manager
+        .create_index(
+            Index::create()
+                .name("idx_meets_path")
+                .table(Meets::Table)
+                .col(Meets::Path)
+                .unique()
+                .to_owned()
+        )
+        .await?;
+
+    manager
+        .create_table(
+            Table::create()
+                .table(Entries::Table)
+                .if_not_exists()
+                .col(ColumnDef::new(Entries::Id).integer().primary_key().auto_increment().not_null())
+                .col(ColumnDef::new(Entries::MeetId).integer().not_null())
+                .col(ColumnDef::new(Entries::Name).string_len(NAME_LENGTH).not_null())
+                .col(ColumnDef::new(Entries::Division).string_len(ENUM_LENGTH).not_null())
+                .col(ColumnDef::new(Entries::Equipment).string_len(ENUM_LENGTH).not_null())
+                .col(ColumnDef::new(Entries::Sex).string_len(ENUM_LENGTH).not_null())
+                .col(ColumnDef::new(Entries::Bodyweight).decimal_len(PRECISION, SCALE).not_null())
+                .col(ColumnDef::new(Entries::WeightClass).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Squat1).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Squat2).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Squat3).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Squat4).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Bench1).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Bench2).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Bench3).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Bench4).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Deadlift1).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Deadlift2).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Deadlift3).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Deadlift4).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::BestSquat).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::BestBench).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::BestDeadlift).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Total).decimal_len(PRECISION, SCALE))
+                .col(ColumnDef::new(Entries::Place).string_len(PLACE_LENGTH))
+                .col(ColumnDef::new(Entries::Event).string_len(ENUM_LENGTH).not_null())
+                .col(ColumnDef::new(Entries::Age).decimal_len(AGE_PRECISION, AGE_SCALE))
+                .col(ColumnDef::new(Entries::AgeClass).string_len(ENUM_LENGTH))
+                .col(ColumnDef::new(Entries::BirthYearClass).string_len(ENUM_LENGTH))
+                .col(ColumnDef::new(Entries::Tested).boolean().not_null().default(false))
+                .col(ColumnDef::new(Entries::Country).string_len(ISO_LENGTH))
+                .col(ColumnDef::new(Entries::DivisionRaw).string_len(NAME_LENGTH).not_null().default(""))
+                .col(ColumnDef::new(Entries::LifterId).integer().not_null())
+                .col(ColumnDef::new(Entries::CyrillicName).string_len(NAME_LENGTH))
+                .col(ColumnDef::new(Entries::JapaneseName).string_len(NAME_LENGTH))
+                .col(ColumnDef::new(Entries::NameNormalized).string_len(NAME_NORMALIZED_LENGTH).not_null().default(""))
+                .foreign_key(
+                    ForeignKey::create()
+                        .from(Entries::Table, Entries::MeetId)
+                        .to(Meets::Table, Meets::Id)
+                        .on_delete(ForeignKeyAction::Cascade)
+                        .on_update(ForeignKeyAction::Cascade)
+                )
+                .foreign_key(
+                    ForeignKey::create()
+                        .name("fk_entries_lifter")
+                        .from(Entries::Table, Entries::LifterId)
+                        .to(Lifters::Table, Lifters::Id)
+                        .on_delete(ForeignKeyAction::Restrict)
+                        .on_update(ForeignKeyAction::Cascade)
+                )
+                .to_owned()
+        )
+        .await?;
+
+    manager
+        .create_index(
+            Index::create()
+                .name("idx_entries_lifter_id")
+                .table(Entries::Table)
+                .col(Entries::LifterId)
+                .to_owned()
+        )
+        .await?;
+
+    manager
+        .create_index(
+            Index::create()
+                .name("idx_entries_name_normalized")
+                .table(Entries::Table)
+                .col(Entries::NameNormalized)
+                .to_owned()
+        )
+        .await?;
+
+    Ok(())
+}
+
+pub(crate) async fn drop(manager: &SchemaManager<'_>) -> Result<(), DbErr> {
+    for table in [Entries::Table.into_iden(), Meets::Table.into_iden(), Lifters::Table.into_iden(), Federations::Table.into_iden()] {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(table)
+                    .if_exists()
+                    .to_owned()
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+#[derive(DeriveIden)]
+enum Federations {
+    Table,
+
+    Id,
+    Code,
+}
+
+#[derive(DeriveIden)]
+enum Lifters {
+    Table,
+
+    Id,
+    Name,
+}
+
+#[derive(DeriveIden)]
+enum Meets {
+    Table,
+
+    Id,
+    Name,
+    Federation,
+    Country,
+    State,
+    Town,
+    Date,
+    Path,
+    Hash,
+}
+
+#[derive(DeriveIden)]
+enum Entries {
+    Table,
+
+    Id,
+    MeetId,
+    Name,
+    Division,
+    Equipment,
+    Sex,
+    Bodyweight,
+    WeightClass,
+    Squat1,
+    Squat2,
+    Squat3,
+    Squat4,
+    Bench1,
+    Bench2,
+    Bench3,
+    Bench4,
+    Deadlift1,
+    Deadlift2,
+    Deadlift3,
+    Deadlift4,
+    BestSquat,
+    BestBench,
+    BestDeadlift,
+    Total,
+    Place,
+    Event,
+    Age,
+    AgeClass,
+    BirthYearClass,
+    Tested,
+    Country,
+    DivisionRaw,
+    LifterId,
+    CyrillicName,
+    JapaneseName,
+    NameNormalized,
+}
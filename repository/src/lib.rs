@@ -1,10 +1,18 @@
+mod pool_options;
 mod read_only_repository;
 mod write_only_repository;
 mod repository;
+#[cfg(feature = "test-database")]
+mod test_database;
 mod traits;
 
 pub mod models;
 
+pub use migrations::MigrationStatus;
+
+pub use pool_options::PoolOptions;
 pub use read_only_repository::ReadOnlyRepository;
 pub use repository::Repository;
+#[cfg(feature = "test-database")]
+pub use test_database::TestDatabase;
 pub use write_only_repository::WriteOnlyRepository;
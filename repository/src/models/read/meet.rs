@@ -1,7 +1,6 @@
 use sea_orm::entity::prelude::*;
 use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
 
-use crate::models::types::{Country, Federation};
 use crate::models::read::ranked_entry;
 
 #[derive(Clone, Debug, Eq, PartialEq, DeriveEntityModel)]
@@ -10,10 +9,13 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub name: String,
-    pub federation: Federation,
-    pub country: Country,
+    pub federation: String,
+    pub country: Option<String>,
     pub state: String,
     pub town: String,
+    pub date: Option<Date>,
+    pub path: Option<String>,
+    pub hash: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -1,2 +1,3 @@
+pub mod federation;
 pub mod meet;
 pub mod ranked_entry;
@@ -2,7 +2,7 @@ use rust_decimal::Decimal;
 use sea_orm::entity::prelude::*;
 use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
 
-use crate::models::types::{Division, Equipment, Sex};
+use crate::models::types::{AgeClass, BirthYearClass, Division, Equipment, Event, Sex};
 use crate::models::read::meet;
 
 #[derive(Clone, Debug, Eq, PartialEq, DeriveEntityModel)]
@@ -12,12 +12,24 @@ pub struct Model {
     pub id: i32,
     pub rank: i64,
     pub meet_id: i32,
+    pub lifter_id: i32,
+    pub place: Option<String>,
     pub name: String,
+    pub cyrillic_name: Option<String>,
+    pub japanese_name: Option<String>,
+    pub name_normalized: String,
     pub division: Division,
+    pub division_raw: String,
     pub equipment: Equipment,
+    pub event: Event,
     pub sex: Sex,
+    pub country: Option<String>,
+    pub age: Option<Decimal>,
+    pub age_class: Option<AgeClass>,
+    pub birth_year_class: Option<BirthYearClass>,
     pub bodyweight: Decimal,
     pub weight_class: Option<Decimal>,
+    pub weight_class_over: bool,
     pub squat1: Option<Decimal>,
     pub squat2: Option<Decimal>,
     pub squat3: Option<Decimal>,
@@ -34,6 +46,7 @@ pub struct Model {
     pub best_bench: Option<Decimal>,
     pub best_deadlift: Option<Decimal>,
     pub total: Option<Decimal>,
+    pub tested: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
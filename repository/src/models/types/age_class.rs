@@ -0,0 +1,100 @@
+use sea_orm::prelude::{DeriveActiveEnum, EnumIter};
+use types::prelude::AgeClassDto;
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "age_class")]
+pub enum AgeClass {
+    #[sea_orm(string_value = "5-12")]
+    Age5To12,
+
+    #[sea_orm(string_value = "13-15")]
+    Age13To15,
+
+    #[sea_orm(string_value = "16-17")]
+    Age16To17,
+
+    #[sea_orm(string_value = "18-19")]
+    Age18To19,
+
+    #[sea_orm(string_value = "20-23")]
+    Age20To23,
+
+    #[sea_orm(string_value = "24-34")]
+    Age24To34,
+
+    #[sea_orm(string_value = "35-39")]
+    Age35To39,
+
+    #[sea_orm(string_value = "40-44")]
+    Age40To44,
+
+    #[sea_orm(string_value = "45-49")]
+    Age45To49,
+
+    #[sea_orm(string_value = "50-54")]
+    Age50To54,
+
+    #[sea_orm(string_value = "55-59")]
+    Age55To59,
+
+    #[sea_orm(string_value = "60-64")]
+    Age60To64,
+
+    #[sea_orm(string_value = "65-69")]
+    Age65To69,
+
+    #[sea_orm(string_value = "70-74")]
+    Age70To74,
+
+    #[sea_orm(string_value = "75-79")]
+    Age75To79,
+
+    #[sea_orm(string_value = "80-999")]
+    Age80To999,
+}
+
+impl From<AgeClassDto> for AgeClass {
+    fn from(value: AgeClassDto) -> Self {
+        match value {
+            AgeClassDto::Age5To12 => Self::Age5To12,
+            AgeClassDto::Age13To15 => Self::Age13To15,
+            AgeClassDto::Age16To17 => Self::Age16To17,
+            AgeClassDto::Age18To19 => Self::Age18To19,
+            AgeClassDto::Age20To23 => Self::Age20To23,
+            AgeClassDto::Age24To34 => Self::Age24To34,
+            AgeClassDto::Age35To39 => Self::Age35To39,
+            AgeClassDto::Age40To44 => Self::Age40To44,
+            AgeClassDto::Age45To49 => Self::Age45To49,
+            AgeClassDto::Age50To54 => Self::Age50To54,
+            AgeClassDto::Age55To59 => Self::Age55To59,
+            AgeClassDto::Age60To64 => Self::Age60To64,
+            AgeClassDto::Age65To69 => Self::Age65To69,
+            AgeClassDto::Age70To74 => Self::Age70To74,
+            AgeClassDto::Age75To79 => Self::Age75To79,
+            AgeClassDto::Age80To999 => Self::Age80To999,
+        }
+    }
+}
+
+impl From<AgeClass> for AgeClassDto {
+    fn from(value: AgeClass) -> Self {
+        match value {
+            AgeClass::Age5To12 => Self::Age5To12,
+            AgeClass::Age13To15 => Self::Age13To15,
+            AgeClass::Age16To17 => Self::Age16To17,
+            AgeClass::Age18To19 => Self::Age18To19,
+            AgeClass::Age20To23 => Self::Age20To23,
+            AgeClass::Age24To34 => Self::Age24To34,
+            AgeClass::Age35To39 => Self::Age35To39,
+            AgeClass::Age40To44 => Self::Age40To44,
+            AgeClass::Age45To49 => Self::Age45To49,
+            AgeClass::Age50To54 => Self::Age50To54,
+            AgeClass::Age55To59 => Self::Age55To59,
+            AgeClass::Age60To64 => Self::Age60To64,
+            AgeClass::Age65To69 => Self::Age65To69,
+            AgeClass::Age70To74 => Self::Age70To74,
+            AgeClass::Age75To79 => Self::Age75To79,
+            AgeClass::Age80To999 => Self::Age80To999,
+        }
+    }
+}
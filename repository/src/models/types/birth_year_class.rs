@@ -0,0 +1,55 @@
+use sea_orm::prelude::{DeriveActiveEnum, EnumIter};
+use types::prelude::BirthYearClassDto;
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "birth_year_class")]
+pub enum BirthYearClass {
+    #[sea_orm(string_value = "14-18")]
+    Age14To18,
+
+    #[sea_orm(string_value = "19-23")]
+    Age19To23,
+
+    #[sea_orm(string_value = "24-39")]
+    Age24To39,
+
+    #[sea_orm(string_value = "40-49")]
+    Age40To49,
+
+    #[sea_orm(string_value = "50-59")]
+    Age50To59,
+
+    #[sea_orm(string_value = "60-69")]
+    Age60To69,
+
+    #[sea_orm(string_value = "70-999")]
+    Age70To999,
+}
+
+impl From<BirthYearClassDto> for BirthYearClass {
+    fn from(value: BirthYearClassDto) -> Self {
+        match value {
+            BirthYearClassDto::Age14To18 => Self::Age14To18,
+            BirthYearClassDto::Age19To23 => Self::Age19To23,
+            BirthYearClassDto::Age24To39 => Self::Age24To39,
+            BirthYearClassDto::Age40To49 => Self::Age40To49,
+            BirthYearClassDto::Age50To59 => Self::Age50To59,
+            BirthYearClassDto::Age60To69 => Self::Age60To69,
+            BirthYearClassDto::Age70To999 => Self::Age70To999,
+        }
+    }
+}
+
+impl From<BirthYearClass> for BirthYearClassDto {
+    fn from(value: BirthYearClass) -> Self {
+        match value {
+            BirthYearClass::Age14To18 => Self::Age14To18,
+            BirthYearClass::Age19To23 => Self::Age19To23,
+            BirthYearClass::Age24To39 => Self::Age24To39,
+            BirthYearClass::Age40To49 => Self::Age40To49,
+            BirthYearClass::Age50To59 => Self::Age50To59,
+            BirthYearClass::Age60To69 => Self::Age60To69,
+            BirthYearClass::Age70To999 => Self::Age70To999,
+        }
+    }
+}
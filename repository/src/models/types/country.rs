@@ -1,21 +1,33 @@
-use sea_orm::prelude::{DeriveActiveEnum, EnumIter};
+use sea_orm::{TryGetError, TryGetable};
 use types::prelude::CountryDto;
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
-#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "country")]
-pub enum Country {
-    #[sea_orm(string_value = "france")]
-    France,
+/// Country stored as its ISO code
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Country(pub CountryDto);
 
-    #[sea_orm(string_value = "other")]
-    Other,
+impl TryGetable for Country {
+    fn try_get_by<I: sea_orm::ColIdx>(res: &sea_orm::QueryResult, index: I) -> Result<Self, TryGetError> {
+        let output = String::try_get_by(res, index)?;
+        CountryDto::from_iso(&output)
+            .map(Self)
+            .ok_or_else(|| TryGetError::DbErr(sea_orm::DbErr::Type(format!("unknown country code \"{output}\""))))
+    }
 }
 
 impl From<CountryDto> for Country {
     fn from(value: CountryDto) -> Self {
-        match value {
-            CountryDto::FRANCE => Self::France,
-            CountryDto::OTHER => Self::Other,
-        }
+        Self(value)
+    }
+}
+
+impl From<Country> for CountryDto {
+    fn from(value: Country) -> Self {
+        value.0
+    }
+}
+
+impl From<Country> for String {
+    fn from(value: Country) -> Self {
+        value.0.iso().to_string()
     }
 }
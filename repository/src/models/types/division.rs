@@ -39,6 +39,9 @@ pub enum Division {
 
     #[sea_orm(string_value = "masters4")]
     Masters4,
+
+    #[sea_orm(string_value = "unknown")]
+    Unknown,
 }
 
 impl From<DivisionDto> for Division {
@@ -56,6 +59,7 @@ impl From<DivisionDto> for Division {
             DivisionDto::Masters2 => Self::Masters2,
             DivisionDto::Masters3 => Self::Masters3,
             DivisionDto::Masters4 => Self::Masters4,
+            DivisionDto::Unknown => Self::Unknown,
         }
     }
 }
@@ -75,6 +79,7 @@ impl From<Division> for DivisionDto {
             Division::Masters2 => Self::Masters2,
             Division::Masters3 => Self::Masters3,
             Division::Masters4 => Self::Masters4,
+            Division::Unknown => Self::Unknown,
         }
     }
 }
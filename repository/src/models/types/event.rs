@@ -0,0 +1,55 @@
+use sea_orm::prelude::{DeriveActiveEnum, EnumIter};
+use types::prelude::EventDto;
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "event")]
+pub enum Event {
+    #[sea_orm(string_value = "sbd")]
+    SBD,
+
+    #[sea_orm(string_value = "bd")]
+    BD,
+
+    #[sea_orm(string_value = "sd")]
+    SD,
+
+    #[sea_orm(string_value = "sb")]
+    SB,
+
+    #[sea_orm(string_value = "s")]
+    S,
+
+    #[sea_orm(string_value = "b")]
+    B,
+
+    #[sea_orm(string_value = "d")]
+    D,
+}
+
+impl From<EventDto> for Event {
+    fn from(value: EventDto) -> Self {
+        match value {
+            EventDto::SBD => Self::SBD,
+            EventDto::BD => Self::BD,
+            EventDto::SD => Self::SD,
+            EventDto::SB => Self::SB,
+            EventDto::S => Self::S,
+            EventDto::B => Self::B,
+            EventDto::D => Self::D,
+        }
+    }
+}
+
+impl From<Event> for EventDto {
+    fn from(value: Event) -> Self {
+        match value {
+            Event::SBD => Self::SBD,
+            Event::BD => Self::BD,
+            Event::SD => Self::SD,
+            Event::SB => Self::SB,
+            Event::S => Self::S,
+            Event::B => Self::B,
+            Event::D => Self::D,
+        }
+    }
+}
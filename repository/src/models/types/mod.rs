@@ -1,17 +1,23 @@
+mod age_class;
+mod birth_year_class;
 mod country;
 mod division;
 mod equipment;
-mod federation;
+mod event;
+mod place;
 mod ranked_entry;
 mod sex;
 mod username;
 mod weight;
 mod weight_class;
 
-pub use country::{Country, CountryIter};
+pub use age_class::{AgeClass, AgeClassIter};
+pub use birth_year_class::{BirthYearClass, BirthYearClassIter};
+pub use country::Country;
 pub use division::{Division, DivisionIter};
 pub use equipment::{Equipment, EquipmentIter};
-pub use federation::{Federation, FederationIter};
+pub use event::{Event, EventIter};
+pub use place::Place;
 pub use ranked_entry::RankedEntry;
 pub use sex::{Sex, SexIter};
 pub use username::Username;
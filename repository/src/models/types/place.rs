@@ -0,0 +1,45 @@
+use sea_orm::{TryGetError, TryGetable};
+use types::prelude::PlaceDto;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Place {
+    P(u8),
+    G,
+    DQ,
+    DD,
+    NS,
+}
+
+impl TryGetable for Place {
+    fn try_get_by<I: sea_orm::ColIdx>(res: &sea_orm::QueryResult, index: I) -> Result<Self, TryGetError> {
+        let output = String::try_get_by(res, index)?;
+        output
+            .parse::<PlaceDto>()
+            .map(Self::from)
+            .map_err(|e| TryGetError::DbErr(sea_orm::DbErr::Type(e.to_string())))
+    }
+}
+
+impl From<PlaceDto> for Place {
+    fn from(value: PlaceDto) -> Self {
+        match value {
+            PlaceDto::P(place) => Self::P(place),
+            PlaceDto::G => Self::G,
+            PlaceDto::DQ => Self::DQ,
+            PlaceDto::DD => Self::DD,
+            PlaceDto::NS => Self::NS,
+        }
+    }
+}
+
+impl From<Place> for PlaceDto {
+    fn from(value: Place) -> Self {
+        match value {
+            Place::P(place) => Self::P(place),
+            Place::G => Self::G,
+            Place::DQ => Self::DQ,
+            Place::DD => Self::DD,
+            Place::NS => Self::NS,
+        }
+    }
+}
@@ -3,17 +3,30 @@ use sea_orm::FromQueryResult;
 
 use types::prelude::EntryDto;
 
-use crate::models::types::{Division, Equipment, Sex, Username, Weight, WeightClass};
+use crate::models::types::{AgeClass, BirthYearClass, Country, Division, Equipment, Event, Place, Sex, Username, Weight, WeightClass};
 
 #[derive(Clone, Debug, Eq, PartialEq, FromQueryResult)]
 pub struct RankedEntry {
     pub rank: i64,
+    pub lifter_id: i32,
+    pub place: Option<Place>,
+    pub federation: String,
     pub name: Username,
+    pub cyrillic_name: Option<String>,
+    pub japanese_name: Option<String>,
+    pub name_normalized: String,
     pub division: Division,
+    pub division_raw: String,
     pub equipment: Equipment,
+    pub event: Event,
     pub sex: Sex,
+    pub country: Option<Country>,
+    pub age: Option<Decimal>,
+    pub age_class: Option<AgeClass>,
+    pub birth_year_class: Option<BirthYearClass>,
     pub bodyweight: Decimal,
-    pub weight_class: Option<WeightClass>,
+    pub weight_class: Option<Weight>,
+    pub weight_class_over: bool,
     pub squat1: Option<Weight>,
     pub squat2: Option<Weight>,
     pub squat3: Option<Weight>,
@@ -30,18 +43,31 @@ pub struct RankedEntry {
     pub best_bench: Option<Weight>,
     pub best_deadlift: Option<Weight>,
     pub total: Option<Weight>,
+    pub tested: bool,
 }
 
 impl From<RankedEntry> for EntryDto {
     fn from(value: RankedEntry) -> Self {
         Self {
             rank: value.rank.into(),
+            lifter_id: value.lifter_id.into(),
+            match_score: None,
+            place: value.place.map(Place::into),
+            federation: Some(value.federation.into()),
             name: value.name.into(),
+            cyrillic_name: value.cyrillic_name,
+            japanese_name: value.japanese_name,
             division: value.division.into(),
+            division_raw: value.division_raw,
             equipment: value.equipment.into(),
+            event: value.event.into(),
             sex: value.sex.into(),
+            country: value.country.map(Country::into),
+            age: value.age.map(Decimal::into),
+            age_class: value.age_class.map(AgeClass::into),
+            birth_year_class: value.birth_year_class.map(BirthYearClass::into),
             bodyweight: value.bodyweight.into(),
-            weight_class: value.weight_class.map(WeightClass::into),
+            weight_class: value.weight_class.map(|limit| WeightClass::new(limit, value.weight_class_over).into()),
             squat1: value.squat1.map(Weight::into),
             squat2: value.squat2.map(Weight::into),
             squat3: value.squat3.map(Weight::into),
@@ -58,6 +84,7 @@ impl From<RankedEntry> for EntryDto {
             best_bench: value.best_bench.map(Weight::into),
             best_deadlift: value.best_deadlift.map(Weight::into),
             total: value.total.map(Weight::into),
+            tested: value.tested,
         }
     }
 }
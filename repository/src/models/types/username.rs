@@ -5,6 +5,7 @@ use types::prelude::UsernameDto;
 pub struct Username {
     pub name: String,
     pub parts: Vec<String>,
+    pub disambiguation: Option<u16>,
 }
 
 impl TryGetable for Username {
@@ -19,38 +20,36 @@ impl Username {
         Self {
             name: String::new(),
             parts: Vec::new(),
+            disambiguation: None,
         }
     }
 
     #[must_use]
-    pub fn new(name: &str, parts: Vec<String>) -> Self {
+    pub fn new(name: &str, parts: Vec<String>, disambiguation: Option<u16>) -> Self {
         Self {
             name: name.to_string(),
             parts,
+            disambiguation,
         }
     }
 }
 
 impl From<UsernameDto> for Username {
     fn from(value: UsernameDto) -> Self {
-        Self::new(&value.name, value.parts)
+        Self::new(&value.name, value.parts, value.disambiguation)
     }
 }
 
 impl From<Username> for UsernameDto {
     fn from(value: Username) -> Self {
-        Self::new(&value.name, value.parts)
+        Self::new(&value.name, value.parts, value.disambiguation)
     }
 }
 
 impl From<String> for Username {
     fn from(value: String) -> Self {
-        let parts: Vec<String> = value
-            .split_whitespace()
-            .filter(|w| !w.is_empty())
-            .map(str::to_lowercase)
-            .collect();
+        let (parts, disambiguation): (Vec<String>, Option<u16>) = UsernameDto::split(&value);
 
-        Self::new(&value, parts)
+        Self::new(&value, parts, disambiguation)
     }
 }
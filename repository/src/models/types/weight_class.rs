@@ -1,5 +1,3 @@
-use rust_decimal::Decimal;
-use sea_orm::TryGetable;
 use types::prelude::*;
 
 use crate::models::types::Weight;
@@ -10,10 +8,14 @@ pub enum WeightClass {
     Over(Weight),
 }
 
-impl TryGetable for WeightClass {
-    fn try_get_by<I: sea_orm::ColIdx>(res: &sea_orm::QueryResult, index: I) -> Result<Self, sea_orm::TryGetError> {
-        let output = Decimal::try_get_by(res, index)?;
-        Ok(output.into())
+impl WeightClass {
+    /// Class stored as its limit and whether it is a "+" class
+    pub const fn new(limit: Weight, over: bool) -> Self {
+        if over {
+            Self::Over(limit)
+        } else {
+            Self::UnderOrEqual(limit)
+        }
     }
 }
 
@@ -34,14 +36,3 @@ impl From<WeightClass> for WeightClassDto {
         }
     }
 }
-
-impl From<Decimal> for WeightClass {
-    fn from(mut value: Decimal) -> Self {
-        if value.is_sign_positive() {
-            Self::Over(value.into())
-        } else {
-            value.set_sign_negative(true);
-            Self::UnderOrEqual(value.into())
-        }
-    }
-}
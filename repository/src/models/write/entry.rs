@@ -3,9 +3,9 @@ use sea_orm::ActiveValue::{NotSet, Set};
 use sea_orm::entity::prelude::*;
 use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
 
-use types::prelude::{EntryDto, WeightClassDto, WeightDto};
+use types::prelude::{AgeClassDto, AgeDto, BirthYearClassDto, EntryDto, WeightClassDto, WeightDto};
 
-use crate::models::types::{Division, Equipment, Sex};
+use crate::models::types::{AgeClass, BirthYearClass, Country, Division, Equipment, Event, Sex};
 use crate::models::write::meet;
 
 #[derive(Clone, Debug, Eq, PartialEq, DeriveEntityModel)]
@@ -14,12 +14,24 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub meet_id: i32,
+    pub lifter_id: i32,
+    pub place: Option<String>,
     pub name: String,
+    pub cyrillic_name: Option<String>,
+    pub japanese_name: Option<String>,
+    pub name_normalized: String,
     pub division: Division,
+    pub division_raw: String,
     pub equipment: Equipment,
+    pub event: Event,
     pub sex: Sex,
+    pub country: Option<String>,
+    pub age: Option<Decimal>,
+    pub age_class: Option<AgeClass>,
+    pub birth_year_class: Option<BirthYearClass>,
     pub bodyweight: Decimal,
     pub weight_class: Option<Decimal>,
+    pub weight_class_over: bool,
     pub squat1: Option<Decimal>,
     pub squat2: Option<Decimal>,
     pub squat3: Option<Decimal>,
@@ -36,6 +48,7 @@ pub struct Model {
     pub best_bench: Option<Decimal>,
     pub best_deadlift: Option<Decimal>,
     pub total: Option<Decimal>,
+    pub tested: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -57,12 +70,24 @@ impl From<EntryDto> for ActiveModel {
         Self {
             id: NotSet,
             meet_id: NotSet,
+            lifter_id: NotSet,
+            place: Set(value.place.map(|place| place.to_string())),
+            name_normalized: Set(value.name_normalized()),
             name: Set(value.name.into()),
+            cyrillic_name: Set(value.cyrillic_name),
+            japanese_name: Set(value.japanese_name),
             division: Set(value.division.into()),
+            division_raw: Set(value.division_raw),
             equipment: Set(value.equipment.into()),
+            event: Set(value.event.into()),
             sex: Set(value.sex.into()),
+            country: Set(value.country.map(|country| Country::from(country).into())),
+            age: Set(value.age.map(AgeDto::into)),
+            age_class: Set(value.age_class.map(AgeClassDto::into)),
+            birth_year_class: Set(value.birth_year_class.map(BirthYearClassDto::into)),
             bodyweight: Set(value.bodyweight.into()),
-            weight_class: Set(value.weight_class.map(WeightClassDto::into)),
+            weight_class: Set(value.weight_class.map(|weight_class| weight_class.limit().into())),
+            weight_class_over: Set(value.weight_class.is_some_and(WeightClassDto::is_over)),
             squat1: Set(value.squat1.map(WeightDto::into)),
             squat2: Set(value.squat2.map(WeightDto::into)),
             squat3: Set(value.squat3.map(WeightDto::into)),
@@ -79,6 +104,7 @@ impl From<EntryDto> for ActiveModel {
             best_bench: Set(value.best_bench.map(WeightDto::into)),
             best_deadlift: Set(value.best_deadlift.map(WeightDto::into)),
             total: Set(value.total.map(WeightDto::into)),
+            tested: Set(value.tested),
         }
     }
 }
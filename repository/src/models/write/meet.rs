@@ -1,9 +1,10 @@
 use sea_orm::ActiveValue::Set;
 use sea_orm::entity::prelude::*;
 use sea_orm::{ActiveModelBehavior, DeriveEntityModel};
-use types::prelude::MeetDataDto;
+use types::prelude::{DateDto, MeetDataDto};
+
+use crate::models::types::Country;
 
-use crate::models::types::{Country, Federation};
 use crate::models::write::entry;
 
 #[derive(Clone, Debug, Eq, PartialEq, DeriveEntityModel)]
@@ -12,10 +13,13 @@ pub struct Model {
     #[sea_orm(primary_key)]
     pub id: i32,
     pub name: String,
-    pub federation: Federation,
-    pub country: Country,
+    pub federation: String,
+    pub country: Option<String>,
     pub state: String,
     pub town: String,
+    pub date: Option<Date>,
+    pub path: Option<String>,
+    pub hash: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -37,9 +41,10 @@ impl From<MeetDataDto> for ActiveModel {
         Self {
             name: Set(value.name),
             federation: Set(value.federation.into()),
-            country: Set(value.country.into()),
+            country: Set(value.country.map(|country| Country::from(country).into())),
             state: Set(value.state),
             town: Set(value.town),
+            date: Set(value.date.map(DateDto::into)),
             ..Default::default()
         }
     }
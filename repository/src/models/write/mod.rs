@@ -1,2 +1,4 @@
 pub mod entry;
+pub mod federation;
+pub mod lifter;
 pub mod meet;
@@ -1,16 +1,25 @@
 use anyhow::{bail, Context, Result};
-use migrations::extension::postgres::PgExpr;
-use migrations::{Asterisk, Expr, Query, SelectStatement};
-use sea_orm::{ColumnTrait, Condition, ConnectOptions, ConnectionTrait, Database, DatabaseConnection, EntityTrait, JoinType, Order, Statement};
+use migrations::{Alias, Asterisk, Expr, Query, SelectStatement};
+use rust_decimal::Decimal;
+use sea_orm::prelude::Date;
+use sea_orm::{ColumnTrait, QueryOrder, QuerySelect, Condition, ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DatabaseTransaction, DbBackend, EntityTrait, JoinType, Order, Statement, TransactionTrait, Value};
 use tracing::debug;
 
-use types::filters::{DivisionFilterDto, FederationFilterDto, QueryDto, SexFilterDto};
-use types::prelude::EntryDto;
+use types::filters::{MatchModeFilterDto, QueryDto, WeightClassFilterDto};
+use types::prelude::{CandidateDto, EntryDto, FederationDto, PlaceDto, SearchResultDto, UsernameDto};
 
-use crate::models::read::{meet, ranked_entry};
-use crate::models::types::{RankedEntry, Username};
-use crate::traits::{IntoQualifiedColumn, QualifiedColumn};
+use crate::models::read::{federation, meet, ranked_entry};
+use crate::models::types::{AgeClass, BirthYearClass, Division, Equipment, Event, RankedEntry, Sex};
+use crate::traits::{IntoQualifiedColumn, QualifiedColumn, QualifiedColumns};
 
+/// Trigram similarity from which a word of a lifter is close enough to a requested word to
+/// score the lifter in the fuzzy mode
+const FUZZY_CANDIDATE_SIMILARITY: f64 = 0.3;
+
+/// Rank of an entry among the entries of its lifter
+const LIFTER_RANK: &str = "lifter_rank";
+
+#[derive(Debug)]
 pub struct ReadOnlyRepository {
     options: ConnectOptions,
     connection: Option<DatabaseConnection>,
@@ -42,7 +51,44 @@ impl ReadOnlyRepository {
         Ok(())
     }
 
-    pub async fn search(&self, query: &QueryDto) -> Result<Vec<EntryDto>> {
+    /// Whether a new connection to the database can be opened. The pool isn't used, its
+    /// connections may all be busy while the database is up
+    pub async fn is_reachable(&self) -> bool {
+        let mut options: ConnectOptions = self.options.clone();
+        options.max_connections(1)
+               .min_connections(0)
+               .connect_lazy(false);
+
+        match Database::connect(options).await {
+            Ok(connection) => {
+                let reachable: bool = connection.ping().await.is_ok();
+                let _ = connection.close().await;
+                reachable
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// Federations of the imported meets, sorted by code
+    pub async fn federations(&self) -> Result<Vec<FederationDto>> {
+        let Some(ref connection) = self.connection else {
+            bail!("Can't read federations without connecting to the database")
+        };
+
+        let federations: Vec<String> = federation::Entity::find()
+            .select_only()
+            .column(federation::Column::Code)
+            .order_by_asc(federation::Column::Code)
+            .into_tuple()
+            .all(connection)
+            .await?;
+
+        Ok(federations.into_iter().map(FederationDto::from).collect())
+    }
+
+    /// Ranks the lifters matching the requested names, one name per line, the names matching
+    /// no lifter or several lifters are reported apart
+    pub async fn search(&self, query: &QueryDto) -> Result<SearchResultDto> {
         let Some(ref connection) = self.connection else {
             bail!("Can't insert meet without connecting to the database")
         };
@@ -50,26 +96,89 @@ impl ReadOnlyRepository {
         let mut ranks_condition: Condition = Condition::all()
             .add(ranked_entry::Column::Total.is_not_null());
 
-        if query.federation_choice != FederationFilterDto::Any {
-            ranks_condition = ranks_condition.add(meet::Column::Federation.eq(query.federation_choice.to_string().to_lowercase()));
+        if let Some(federations) = query.federations() {
+            ranks_condition = ranks_condition.add(meet::Column::Federation.is_in(federations.into_iter().map(FederationDto::to_string)));
+        }
+
+        if let Some(country) = query.meet_country_choice.country() {
+            ranks_condition = ranks_condition.add(meet::Column::Country.eq(country.iso()));
+        }
+
+        if let Some(country) = query.lifter_country_choice.country() {
+            ranks_condition = ranks_condition.add(ranked_entry::Column::Country.eq(country.iso()));
+        }
+
+        if let Some(sexes) = query.sexes() {
+            ranks_condition = ranks_condition.add(ranked_entry::Column::Sex.is_in(sexes.into_iter().map(Sex::from)));
+        }
+
+        if let Some(age_class) = query.age_class_choice.age_class() {
+            ranks_condition = ranks_condition.add(ranked_entry::Column::AgeClass.eq(AgeClass::from(age_class)));
+        }
+
+        if let Some(birth_year_class) = query.birth_year_class_choice.birth_year_class() {
+            ranks_condition = ranks_condition.add(ranked_entry::Column::BirthYearClass.eq(BirthYearClass::from(birth_year_class)));
+        }
+
+        if let Some(divisions) = query.divisions() {
+            ranks_condition = ranks_condition.add(ranked_entry::Column::Division.is_in(divisions.into_iter().map(Division::from)));
         }
 
-        if query.sex_choice != SexFilterDto::Any {
-            ranks_condition = ranks_condition.add(ranked_entry::Column::Sex.eq(query.sex_choice.to_string().to_lowercase()));
+        if let WeightClassFilterDto::Class { set, sex, class } = query.weight_class_choice {
+            ranks_condition = ranks_condition.add(ranked_entry::Column::Sex.eq(Sex::from(sex)));
+
+            if query.rank_by_bodyweight {
+                let Some((lower, upper)) = set.bodyweights(sex, class) else {
+                    bail!("weight class {class} isn't a class of {set}")
+                };
+
+                ranks_condition = ranks_condition.add(ranked_entry::Column::Bodyweight.gt(Decimal::from(lower)));
+                if let Some(upper) = upper {
+                    ranks_condition = ranks_condition.add(ranked_entry::Column::Bodyweight.lte(Decimal::from(upper)));
+                }
+            } else {
+                ranks_condition = ranks_condition
+                    .add(ranked_entry::Column::WeightClass.eq(Decimal::from(class.limit())))
+                    .add(ranked_entry::Column::WeightClassOver.eq(class.is_over()));
+            }
+        }
+
+        if let Some(equipments) = query.equipments() {
+            ranks_condition = ranks_condition.add(ranked_entry::Column::Equipment.is_in(equipments.into_iter().map(Equipment::from)));
+        }
+
+        ranks_condition = ranks_condition.add(ranked_entry::Column::Event.eq(Event::from(query.event_choice.event())));
+
+        if let Some(tested) = query.tested_choice.tested() {
+            ranks_condition = ranks_condition.add(ranked_entry::Column::Tested.eq(tested));
+        }
+
+        if query.exclude_disqualified {
+            ranks_condition = ranks_condition.add(
+                Condition::any()
+                    .add(ranked_entry::Column::Place.is_null())
+                    .add(ranked_entry::Column::Place.is_not_in(PlaceDto::DISQUALIFIED.map(|place| place.to_string())))
+            );
         }
 
-        if query.division_choice != DivisionFilterDto::Any {
-            ranks_condition = ranks_condition.add(ranked_entry::Column::Division.eq(query.division_choice.to_string().to_lowercase()));
+        if let Some(date_from) = query.date_from {
+            ranks_condition = ranks_condition.add(meet::Column::Date.gte(Date::from(date_from)));
         }
 
-        ranks_condition = ranks_condition.add(ranked_entry::Column::Equipment.eq(query.equipment_choice.to_string().to_lowercase()));
+        if let Some(date_to) = query.date_to {
+            ranks_condition = ranks_condition.add(meet::Column::Date.lte(Date::from(date_to)));
+        }
 
-        let ranks: SelectStatement = Query::select()
+        // The best entry of each lifter, a window rather than `DISTINCT ON` which sqlite lacks
+        let best_entries: SelectStatement = Query::select()
             .from(ranked_entry::Entity)
-            .distinct_on([ranked_entry::Column::Name.into_qualified()])
             .qualified_column(ranked_entry::Column::Id)
-            .qualified_column(ranked_entry::Column::Name)
+            .qualified_column(ranked_entry::Column::LifterId)
             .qualified_column(ranked_entry::Column::Total)
+            .expr_as(
+                Expr::cust(r#"ROW_NUMBER() OVER (PARTITION BY "entries"."lifter_id" ORDER BY "entries"."total" DESC, "entries"."id" ASC)"#),
+                Alias::new(LIFTER_RANK),
+            )
             .join(
                 JoinType::LeftJoin, 
                 meet::Entity,
@@ -77,64 +186,93 @@ impl ReadOnlyRepository {
                     .equals(meet::Column::Id.into_qualified())
             )
             .cond_where(ranks_condition)
-            .order_by_columns([
-                (ranked_entry::Column::Name.into_qualified(), Order::Asc),
-                (ranked_entry::Column::Total.into_qualified(), Order::Desc),
-            ])
             .to_owned();
 
         let ranks: SelectStatement =Query::select()
-            .from_subquery(ranks, "ranks")
+            .from_subquery(best_entries, "best_entries")
             .column(Asterisk)
             .expr_as(
-                Expr::cust("ROW_NUMBER() OVER (ORDER BY \"total\" DESC)"),
+                Expr::cust("ROW_NUMBER() OVER (ORDER BY \"total\" DESC, \"id\" ASC)"),
                 ranked_entry::Column::Rank,
             )
+            .and_where(Expr::col(Alias::new(LIFTER_RANK)).eq(1))
             .order_by_columns([
                 (ranked_entry::Column::Total, Order::Desc),
             ])
             .to_owned();
 
+        // Sqlite has no trigram similarity, all the candidates of the fuzzy mode are scored
+        let prefilter_names: bool = query.match_mode_choice == MatchModeFilterDto::Exact
+            || connection.get_database_backend() == DbBackend::Postgres;
         let mut condition: Condition = Condition::any();
 
-        for line in query.powerlifters.lines() {
-            let mut part_condition = Condition::all();
+        for line in query.powerlifters.lines().filter(|_| prefilter_names) {
+            let (parts, _): (Vec<String>, Option<u16>) = UsernameDto::split(line);
+            // A typo in a word shouldn't discard the lifter, the candidates of the fuzzy mode
+            // only need one similar word and are scored afterwards
+            let mut part_condition = match query.match_mode_choice {
+                MatchModeFilterDto::Exact => Condition::all(),
+                MatchModeFilterDto::Fuzzy => Condition::any(),
+            };
 
-            for part in line.split_whitespace() {
-                let format: String = format!("%{part}%");
-                part_condition = part_condition.add(Expr::column(("ranks", ranked_entry::Column::Name)).ilike(format));
+            for part in parts {
+                part_condition = match query.match_mode_choice {
+                    MatchModeFilterDto::Exact => part_condition.add(
+                        Expr::col(ranked_entry::Column::NameNormalized.into_qualified()).like(format!("%{part}%"))
+                    ),
+                    // The operator rather than `word_similarity` so the trigram index is used
+                    MatchModeFilterDto::Fuzzy => part_condition.add(
+                        Expr::cust_with_values(r#"$1 <% "entries"."name_normalized""#, [Value::from(part)])
+                    ),
+                };
             }
 
             condition = condition.add(part_condition);
         }
 
-        let result: SelectStatement = Query::select()
+        let mut result: SelectStatement = Query::select()
             .from(ranked_entry::Entity)
             .column(ranked_entry::Column::Rank)
             .qualified_column(ranked_entry::Column::Id)
             .qualified_column(ranked_entry::Column::MeetId)
+            .qualified_column(ranked_entry::Column::LifterId)
+            .qualified_column(ranked_entry::Column::Place)
+            .qualified_column(meet::Column::Federation)
             .qualified_column(ranked_entry::Column::Name)
+            .qualified_column(ranked_entry::Column::CyrillicName)
+            .qualified_column(ranked_entry::Column::JapaneseName)
+            .qualified_column(ranked_entry::Column::NameNormalized)
             .qualified_column_casted(ranked_entry::Column::Division, "text")
+            .qualified_column(ranked_entry::Column::DivisionRaw)
             .qualified_column_casted(ranked_entry::Column::Equipment, "text")
+            .qualified_column_casted(ranked_entry::Column::Event, "text")
             .qualified_column_casted(ranked_entry::Column::Sex, "text")
+            .qualified_column(ranked_entry::Column::Country)
+            .qualified_column(ranked_entry::Column::Age)
+            .qualified_column_casted(ranked_entry::Column::AgeClass, "text")
+            .qualified_column_casted(ranked_entry::Column::BirthYearClass, "text")
             .qualified_column(ranked_entry::Column::Bodyweight)
             .qualified_column(ranked_entry::Column::WeightClass)
-            .qualified_column(ranked_entry::Column::Squat1)
-            .qualified_column(ranked_entry::Column::Squat2)
-            .qualified_column(ranked_entry::Column::Squat3)
-            .qualified_column(ranked_entry::Column::Squat4)
-            .qualified_column(ranked_entry::Column::Bench1)
-            .qualified_column(ranked_entry::Column::Bench2)
-            .qualified_column(ranked_entry::Column::Bench3)
-            .qualified_column(ranked_entry::Column::Bench4)
-            .qualified_column(ranked_entry::Column::Deadlift1)
-            .qualified_column(ranked_entry::Column::Deadlift2)
-            .qualified_column(ranked_entry::Column::Deadlift3)
-            .qualified_column(ranked_entry::Column::Deadlift4)
-            .qualified_column(ranked_entry::Column::BestSquat)
-            .qualified_column(ranked_entry::Column::BestBench)
-            .qualified_column(ranked_entry::Column::BestDeadlift)
-            .qualified_column(ranked_entry::Column::Total)
+            .qualified_column(ranked_entry::Column::WeightClassOver)
+            .qualified_columns([
+                ranked_entry::Column::Squat1,
+                ranked_entry::Column::Squat2,
+                ranked_entry::Column::Squat3,
+                ranked_entry::Column::Squat4,
+                ranked_entry::Column::Bench1,
+                ranked_entry::Column::Bench2,
+                ranked_entry::Column::Bench3,
+                ranked_entry::Column::Bench4,
+                ranked_entry::Column::Deadlift1,
+                ranked_entry::Column::Deadlift2,
+                ranked_entry::Column::Deadlift3,
+                ranked_entry::Column::Deadlift4,
+                ranked_entry::Column::BestSquat,
+                ranked_entry::Column::BestBench,
+                ranked_entry::Column::BestDeadlift,
+                ranked_entry::Column::Total,
+                ranked_entry::Column::Tested,
+            ].into_iter())
             .join_subquery(
                 JoinType::RightJoin, 
                 ranks,
@@ -142,44 +280,45 @@ impl ReadOnlyRepository {
                 Expr::col(("ranks", ranked_entry::Column::Id))
                 .equals(ranked_entry::Column::Id.into_qualified())
             )
+            .join(
+                JoinType::LeftJoin,
+                meet::Entity,
+                Expr::col(ranked_entry::Column::MeetId.into_qualified())
+                    .equals(meet::Column::Id.into_qualified())
+            )
             .order_by(ranked_entry::Column::Rank, sea_orm::Order::Asc)
-            .cond_where(condition)
             .to_owned();
 
+        if prefilter_names {
+            result.cond_where(condition);
+        }
+
         let statement: Statement = connection.get_database_backend().build(&result);
         debug!("sql query:\n{:?}", statement.to_string());
+        // `<%` compares the similarity to a setting rather than to a parameter, the search sets
+        // it for its own transaction
+        let transaction: DatabaseTransaction = connection.begin().await?;
+        if prefilter_names && query.match_mode_choice == MatchModeFilterDto::Fuzzy {
+            transaction
+                .execute_unprepared(&format!("SET LOCAL pg_trgm.word_similarity_threshold = {FUZZY_CANDIDATE_SIMILARITY}"))
+                .await?;
+        }
         let result = ranked_entry::Entity::find().from_raw_sql(statement);
         let sea_entries: Vec<RankedEntry> = result
             .into_model::<RankedEntry>()
-            .all(connection)
+            .all(&transaction)
             .await?;
+        transaction.commit().await?;
 
-        let mut output: Vec<EntryDto> = Vec::new();
-
-        for powerlifter in query.powerlifters.lines() {
-            let username: Username = Username::from(powerlifter.to_string());
-            let entry: Option<RankedEntry> = sea_entries
-                .iter()
-                .find(|x| {
-                    if x.name.parts.len() < username.parts.len() {
-                        return false;
-                    }
-
-                    for part in &username.parts {
-                        if !x.name.parts.contains(part) {
-                            return false;
-                        }
-                    }
-
-                    true
-                })
-                .cloned();
-
-            if let Some(entry) = entry {
-                output.push(entry.into());
-            }
-        }
+        let entries: Vec<(i64, String, EntryDto)> = sea_entries
+            .into_iter()
+            .map(|entry| (entry.rank, entry.name_normalized.clone(), EntryDto::from(entry)))
+            .collect();
+        let candidates: Vec<CandidateDto> = entries
+            .iter()
+            .map(|(rank, name_normalized, entry)| CandidateDto { rank: *rank, entry, name_normalized })
+            .collect();
 
-        Ok(output)
+        Ok(SearchResultDto::from_candidates(&query.powerlifters, query.match_mode_choice, &candidates))
     }
 }
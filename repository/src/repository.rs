@@ -2,10 +2,9 @@ use anyhow::{Context, Result};
 use dotenvy::dotenv;
 use sea_orm::ConnectOptions;
 use std::env;
-use std::time::Duration;
 use tracing::info;
 
-use crate::{ReadOnlyRepository, WriteOnlyRepository};
+use crate::{PoolOptions, ReadOnlyRepository, WriteOnlyRepository};
 
 pub struct Repository;
 
@@ -19,31 +18,55 @@ impl Repository {
         Ok(format!("postgres://{username}:{password}@{host}/{database}"))
     }
 
-    fn build_connection_options() -> Result<ConnectOptions> {
-        dotenv()?;
-        let connection_string = Self::build_connection_string()?;
-        let database_schema = env::var("DATABASE_SCHEMA").context("DATABASE_PASSWORD must be set")?;
+    /// `DATABASE_URL` when it is set, for instance `sqlite://powerlifting.db?mode=rwc` with the
+    /// `sqlite` feature, otherwise the Postgres database of the `DATABASE_*` variables
+    pub(crate) fn connection_url() -> Result<String> {
+        dotenv().ok();
 
-        info!("Repository setting up for {}", connection_string);
-        let mut connection: ConnectOptions = ConnectOptions::new(connection_string);
-        connection.set_schema_search_path(database_schema)
-                  .acquire_timeout(Duration::from_secs(3))
+        match env::var("DATABASE_URL") {
+            Ok(url) => Ok(url),
+            Err(_) => Self::build_connection_string(),
+        }
+    }
+
+    fn build_connection_options(url: &str, pool: &PoolOptions) -> ConnectOptions {
+        info!("Repository setting up for {}", url);
+        let mut connection: ConnectOptions = ConnectOptions::new(url);
+        if let Ok(database_schema) = env::var("DATABASE_SCHEMA") {
+            connection.set_schema_search_path(database_schema);
+        }
+        connection.max_connections(pool.max_connections)
+                  .min_connections(pool.min_connections)
+                  .connect_timeout(pool.connect_timeout)
+                  .acquire_timeout(pool.acquire_timeout)
+                  .idle_timeout(pool.idle_timeout)
                   .test_before_acquire(true)
                   .connect_lazy(true)
                   .sqlx_logging(false);
 
-        Ok(connection)
+        connection
     }
 
     pub fn write_only() -> Result<WriteOnlyRepository> {
-        let options: ConnectOptions = Self::build_connection_options()?;
+        Ok(Self::write_only_at(&Self::connection_url()?))
+    }
 
-        Ok(WriteOnlyRepository::new(options))
+    /// Write only repository of the database at the given url
+    pub fn write_only_at(url: &str) -> WriteOnlyRepository {
+        WriteOnlyRepository::new(Self::build_connection_options(url, &PoolOptions::default()))
     }
 
     pub fn read_only() -> Result<ReadOnlyRepository> {
-        let options: ConnectOptions = Self::build_connection_options()?;
+        Self::read_only_with_pool(&PoolOptions::default())
+    }
+
+    /// Read only repository whose connection is a pool meant to be shared by the requests
+    pub fn read_only_with_pool(pool: &PoolOptions) -> Result<ReadOnlyRepository> {
+        Ok(Self::read_only_at(&Self::connection_url()?, pool))
+    }
 
-        Ok(ReadOnlyRepository::new(options))
+    /// Read only repository of the database at the given url
+    pub fn read_only_at(url: &str, pool: &PoolOptions) -> ReadOnlyRepository {
+        ReadOnlyRepository::new(Self::build_connection_options(url, pool))
     }
 }
@@ -0,0 +1,126 @@
+use anyhow::{Context, Result};
+use sea_orm::{ConnectionTrait, Database};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use tracing::warn;
+
+use crate::Repository;
+
+static DATABASES: AtomicUsize = AtomicUsize::new(0);
+
+/// Database created for a test and removed with it
+pub struct TestDatabase {
+    location: Location,
+}
+
+enum Location {
+    Postgres {
+        server_url: String,
+        name: String,
+    },
+    Sqlite {
+        path: PathBuf,
+    },
+}
+
+impl TestDatabase {
+    /// Postgres database created on the server of `TEST_DATABASE_URL`, or else on the server of
+    /// the environment, whose database is left untouched
+    pub async fn create() -> Result<Self> {
+        let server_url: String = match env::var("TEST_DATABASE_URL") {
+            Ok(url) => url,
+            Err(_) => Repository::connection_url()?,
+        };
+        let name: String = Self::name();
+
+        let connection = Database::connect(server_url.as_str())
+            .await
+            .context("failed to connect to the test database server")?;
+        connection
+            .execute_unprepared(&format!(r#"CREATE DATABASE "{name}""#))
+            .await
+            .with_context(|| format!("failed to create test database {name}"))?;
+        connection.close().await?;
+
+        Ok(Self { location: Location::Postgres { server_url, name } })
+    }
+
+    /// Sqlite file in the temporary directory, created by the first connection
+    pub fn sqlite() -> Self {
+        let path: PathBuf = env::temp_dir().join(format!("{}.db", Self::name()));
+        let _ = fs::remove_file(&path);
+
+        Self { location: Location::Sqlite { path } }
+    }
+
+    fn name() -> String {
+        format!("powerlifting_test_{}_{}", process::id(), DATABASES.fetch_add(1, Ordering::Relaxed))
+    }
+
+    pub fn url(&self) -> String {
+        match self.location {
+            Location::Postgres { ref server_url, ref name } => with_database(server_url, name),
+            Location::Sqlite { ref path } => format!("sqlite://{}?mode=rwc", path.display()),
+        }
+    }
+}
+
+impl Drop for TestDatabase {
+    fn drop(&mut self) {
+        let (server_url, name): (String, String) = match self.location {
+            Location::Postgres { ref server_url, ref name } => (server_url.clone(), name.clone()),
+            Location::Sqlite { ref path } => {
+                let _ = fs::remove_file(path);
+                return;
+            },
+        };
+
+        // The runtime of the test can't block on the drop, the drop gets a runtime of its own
+        let dropped = thread::spawn({
+            let name: String = name.clone();
+            move || -> Result<()> {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()?
+                    .block_on(async {
+                        let connection = Database::connect(server_url.as_str()).await?;
+                        connection
+                            .execute_unprepared(&format!(r#"DROP DATABASE IF EXISTS "{name}" WITH (FORCE)"#))
+                            .await?;
+                        connection.close().await?;
+
+                        Ok(())
+                    })
+            }
+        })
+        .join();
+
+        match dropped {
+            Ok(Ok(())) => {},
+            Ok(Err(e)) => warn!("failed to drop test database {name}: {e:#}"),
+            Err(_) => warn!("failed to drop test database {name}"),
+        }
+    }
+}
+
+/// Url of another database of the server, the parameters of the url are kept
+fn with_database(url: &str, database: &str) -> String {
+    let (server, parameters): (&str, &str) = match url.split_once('?') {
+        Some((server, parameters)) => (server, parameters),
+        None => (url, ""),
+    };
+    let server: &str = match server.split_once("://") {
+        Some((scheme, rest)) => &server[..scheme.len() + 3 + rest.find('/').unwrap_or(rest.len())],
+        None => server,
+    };
+
+    if parameters.is_empty() {
+        format!("{server}/{database}")
+    } else {
+        format!("{server}/{database}?{parameters}")
+    }
+}
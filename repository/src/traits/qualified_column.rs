@@ -1,4 +1,4 @@
-use migrations::{ExprTrait, IntoIden, SelectStatement};
+use migrations::{IntoIden, SelectStatement};
 
 use crate::traits::IntoQualifiedColumn;
 
@@ -24,6 +24,8 @@ impl QualifiedColumn for SelectStatement {
         T: IntoQualifiedColumn,
         C: IntoIden
     {
-        self.expr(column.into_qualified().cast_as(cast_as))
+        // Postgres names a cast after its column, sqlite after the whole expression
+        let alias = column.into_iden();
+        self.expr_as(column.into_qualified_casted(cast_as), alias)
     }
 }
@@ -1,24 +1,55 @@
 use anyhow::{bail, Context, Error, Result};
-use migrations::{Migrator, MigratorTrait};
-use sea_orm::{ActiveModelTrait, ConnectOptions, Database, DatabaseConnection, EntityTrait, TransactionTrait};
+use migrations::{MigrationStatus, Migrator, MigratorTrait};
+use sea_orm::ActiveValue::Set;
+use migrations::{OnConflict, Query};
+use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectOptions, ConnectionTrait, Database, DbBackend, DatabaseConnection, EntityTrait, Iterable, QueryFilter, QuerySelect, TransactionTrait};
+use std::collections::{HashMap, HashSet};
+use std::mem;
 use tracing::info;
 use types::prelude::*;
 
-use crate::models::write::{entry, meet};
+use crate::models::write::{entry, federation, lifter, meet};
+
+/// Postgres rejects the statements with more bind parameters
+const MAX_POSTGRES_BIND_PARAMETERS: usize = 65_535;
+/// Default limit of sqlite since 3.32
+const MAX_SQLITE_BIND_PARAMETERS: usize = 32_766;
 
 pub struct WriteOnlyRepository {
     options: ConnectOptions,
     connection: Option<DatabaseConnection>,
+    batch_size: usize,
 }
 
 impl WriteOnlyRepository {
+    /// Number of rows inserted by a statement
+    pub const DEFAULT_BATCH_SIZE: usize = 1_000;
+
     pub(crate) const fn new(options: ConnectOptions) -> Self {
         Self {
             options,
             connection: None,
+            batch_size: Self::DEFAULT_BATCH_SIZE,
         }
     }
 
+    /// Sets the number of rows inserted by a statement, it is capped by the number of bind
+    /// parameters of a statement of the database
+    #[must_use]
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    fn max_batch_size(backend: DbBackend) -> usize {
+        let max_bind_parameters: usize = match backend {
+            DbBackend::Sqlite => MAX_SQLITE_BIND_PARAMETERS,
+            _ => MAX_POSTGRES_BIND_PARAMETERS,
+        };
+
+        max_bind_parameters / entry::Column::iter().count()
+    }
+
     pub async fn connect(&mut self) -> Result<()> {
         match Database::connect(self.options.clone()).await {
             Ok(connection) => {
@@ -37,44 +68,312 @@ impl WriteOnlyRepository {
         Ok(())
     }
 
-    pub async fn refresh_migrations(&self)-> Result<()> {
+    /// Applies the pending migrations, the database must not have been migrated by a newer version
+    pub async fn apply_migrations(&self)-> Result<()> {
+        self.check_schema().await?;
+
         match self.connection {
-            Some(ref connection) => Migrator::refresh(connection).await.context("failed to refresh migrations"),
+            Some(ref connection) => Migrator::up(connection, None).await.context("failed to apply migrations"),
             None => bail!("Can't apply migrations without connecting to the database"),
         }
     }
 
-    pub async fn insert_meet(&mut self, meet: MeetDto) -> Result<()> {
+    /// Rolls back the last `steps` applied migrations
+    pub async fn rollback_migrations(&self, steps: u32)-> Result<()> {
+        self.check_schema().await?;
+
+        match self.connection {
+            Some(ref connection) => Migrator::down(connection, Some(steps)).await.context("failed to roll back migrations"),
+            None => bail!("Can't roll back migrations without connecting to the database"),
+        }
+    }
+
+    /// Known migrations in the order they are applied, with their status
+    pub async fn migration_status(&self) -> Result<Vec<(String, MigrationStatus)>> {
+        let Some(ref connection) = self.connection else {
+            bail!("Can't read migrations without connecting to the database")
+        };
+
+        self.check_schema().await?;
+
+        Ok(Migrator::get_migration_with_status(connection)
+            .await?
+            .into_iter()
+            .map(|migration| (migration.name().to_string(), migration.status()))
+            .collect())
+    }
+
+    /// Fails if the database contains migrations this version doesn't know
+    async fn check_schema(&self) -> Result<()> {
         let Some(ref connection) = self.connection else {
-            bail!("Can't insert meet without connecting to the database")
+            bail!("Can't read migrations without connecting to the database")
         };
 
-        let meet_name: String = meet.data.name.clone();
-        info!("Inserting meet {meet_name}");
+        let known: HashSet<String> = Migrator::migrations()
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect();
+        let unknown: Vec<String> = Migrator::get_migration_models(connection)
+            .await?
+            .into_iter()
+            .map(|model| model.version)
+            .filter(|version| !known.contains(version))
+            .collect();
+
+        if !unknown.is_empty() {
+            bail!("unknown schema version, the database has been migrated by a newer version: {}", unknown.join(", "));
+        }
+
+        Ok(())
+    }
+
+    /// Hashes of the imported meets, indexed by path
+    pub async fn meet_hashes(&self) -> Result<HashMap<String, String>> {
+        let Some(ref connection) = self.connection else {
+            bail!("Can't read meets without connecting to the database")
+        };
+
+        let meets: Vec<(Option<String>, Option<String>)> = meet::Entity::find()
+            .select_only()
+            .column(meet::Column::Path)
+            .column(meet::Column::Hash)
+            .into_tuple()
+            .all(connection)
+            .await?;
+
+        Ok(meets
+            .into_iter()
+            .filter_map(|(path, hash)| Some((path?, hash.unwrap_or_default())))
+            .collect())
+    }
+
+    /// Inserts the meet, or replaces it and its entries if a meet with the same path exists
+    pub async fn upsert_meet(&mut self, meet: MeetDto) -> Result<()> {
+        self.upsert_meets([meet]).await
+    }
+
+    /// Inserts the meets, or replaces them and their entries if meets with the same paths exist.
+    /// The meets are written in groups of about `batch_size` entries, a transaction per group,
+    /// so the rows of the small meets share statements
+    pub async fn upsert_meets(&mut self, meets: impl IntoIterator<Item = MeetDto>) -> Result<()> {
+        let Some(ref connection) = self.connection else {
+            bail!("Can't insert meets without connecting to the database")
+        };
+
+        let batch_size: usize = self.batch_size.min(Self::max_batch_size(connection.get_database_backend()));
+        let mut group: Vec<MeetDto> = Vec::new();
+        let mut group_entries: usize = 0;
+
+        for meet in meets {
+            group_entries += meet.entries.len();
+            group.push(meet);
+
+            if group_entries >= batch_size {
+                Self::upsert_group(connection, mem::take(&mut group), batch_size).await?;
+                group_entries = 0;
+            }
+        }
+
+        if !group.is_empty() {
+            Self::upsert_group(connection, group, batch_size).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn upsert_group(connection: &DatabaseConnection, meets: Vec<MeetDto>, batch_size: usize) -> Result<()> {
+        let first_meet: String = meets.first().map(|meet| meet.data.name.clone()).unwrap_or_default();
+        let meet_count: usize = meets.len();
+        info!("Inserting {meet_count} meets from meet {first_meet}");
 
         connection.transaction::<_, (), Error>(|connection| {
             Box::pin(async move {
-                let new_meet: meet::ActiveModel = meet.data.into();
-                let inserted_id: Option<i32> = meet::Entity::insert(new_meet)
-                    .exec_with_returning_keys(connection)
-                    .await?
-                    .first()
-                    .copied();
+                let mut federations: Vec<String> = meets
+                    .iter()
+                    .map(|meet| meet.data.federation.to_string())
+                    .collect();
+                federations.sort_unstable();
+                federations.dedup();
+                federation::Entity::insert_many(federations.into_iter().map(|code| federation::ActiveModel {
+                        code: Set(code),
+                        ..Default::default()
+                    }))
+                    .on_conflict(
+                        OnConflict::column(federation::Column::Code)
+                            .do_nothing()
+                            .to_owned()
+                    )
+                    .do_nothing()
+                    .exec(connection)
+                    .await?;
+
+                let paths: Vec<String> = meets.iter().map(|meet| meet.path.clone()).collect();
+                let existing_meets: HashMap<String, i32> = Self::meet_ids(connection, &paths, batch_size).await?;
+
+                let existing_ids: Vec<i32> = existing_meets.values().copied().collect();
+                for existing_ids in existing_ids.chunks(batch_size) {
+                    entry::Entity::delete_many()
+                        .filter(entry::Column::MeetId.is_in(existing_ids.iter().copied()))
+                        .exec(connection)
+                        .await?;
+                }
+
+                let mut new_meets: Vec<meet::ActiveModel> = Vec::new();
+                let mut meet_entries: Vec<(String, Vec<EntryDto>)> = Vec::with_capacity(meets.len());
+                for meet in meets {
+                    let mut new_meet: meet::ActiveModel = meet.data.into();
+                    new_meet.path = Set(Some(meet.path.clone()));
+                    new_meet.hash = Set(Some(meet.hash));
+
+                    if let Some(&id) = existing_meets.get(&meet.path) {
+                        new_meet.id = Set(id);
+                        new_meet.update(connection).await?;
+                    } else {
+                        new_meets.push(new_meet);
+                    }
+
+                    meet_entries.push((meet.path, meet.entries));
+                }
+
+                for new_meets in new_meets.chunks(batch_size) {
+                    meet::Entity::insert_many(new_meets.to_vec())
+                        .exec(connection)
+                        .await?;
+                }
+
+                let meet_ids: HashMap<String, i32> = Self::meet_ids(connection, &paths, batch_size).await?;
+
+                let mut names: Vec<String> = meet_entries
+                    .iter()
+                    .flat_map(|(_, entries)| entries.iter().map(|entry| entry.name.name.clone()))
+                    .collect();
+                names.sort_unstable();
+                names.dedup();
+                let mut lifter_ids: HashMap<String, i32> = HashMap::with_capacity(names.len());
+                for names in names.chunks(batch_size) {
+                    lifter::Entity::insert_many(names.iter().map(|name| lifter::ActiveModel {
+                            name: Set(name.clone()),
+                            ..Default::default()
+                        }))
+                        .on_conflict(
+                            OnConflict::column(lifter::Column::Name)
+                                .do_nothing()
+                                .to_owned()
+                        )
+                        .do_nothing()
+                        .exec(connection)
+                        .await?;
+
+                    lifter_ids.extend(
+                        lifter::Entity::find()
+                            .select_only()
+                            .column(lifter::Column::Name)
+                            .column(lifter::Column::Id)
+                            .filter(lifter::Column::Name.is_in(names))
+                            .into_tuple::<(String, i32)>()
+                            .all(connection)
+                            .await?
+                    );
+                }
 
-                if let Some(meet_id) = inserted_id {
-                    for entry in meet.entries {
+                let mut new_entries: Vec<entry::ActiveModel> = Vec::new();
+                for (path, entries) in meet_entries {
+                    let meet_id: i32 = *meet_ids
+                        .get(&path)
+                        .with_context(|| format!("meet {path} should exist"))?;
+
+                    for entry in entries {
+                        let lifter_id: i32 = *lifter_ids
+                            .get(&entry.name.name)
+                            .with_context(|| format!("lifter {} should exist", entry.name.name))?;
                         let mut new_entry: entry::ActiveModel = entry.into();
                         new_entry.set(entry::Column::MeetId, meet_id.into());
-                        entry::Entity::insert(new_entry)
-                            .exec(connection)
-                            .await?;
+                        new_entry.set(entry::Column::LifterId, lifter_id.into());
+                        new_entries.push(new_entry);
                     }
                 }
 
+                for new_entries in new_entries.chunks(batch_size) {
+                    entry::Entity::insert_many(new_entries.to_vec())
+                        .exec(connection)
+                        .await?;
+                }
+
                 Ok(())
             })
         })
         .await
-        .context(format!("failed to insert meet {meet_name}"))
+        .context(format!("failed to insert {meet_count} meets from meet {first_meet}"))
+    }
+
+    /// Ids of the meets with the given paths, indexed by path
+    async fn meet_ids(connection: &impl ConnectionTrait, paths: &[String], batch_size: usize) -> Result<HashMap<String, i32>> {
+        let mut ids: HashMap<String, i32> = HashMap::with_capacity(paths.len());
+
+        for paths in paths.chunks(batch_size) {
+            ids.extend(
+                meet::Entity::find()
+                    .select_only()
+                    .column(meet::Column::Path)
+                    .column(meet::Column::Id)
+                    .filter(meet::Column::Path.is_in(paths))
+                    .into_tuple::<(String, i32)>()
+                    .all(connection)
+                    .await?
+            );
+        }
+
+        Ok(ids)
+    }
+
+    /// Deletes the meets with the given paths, along with the meets imported before paths were
+    /// tracked, and the federations and lifters which don't have meets anymore
+    pub async fn delete_meets(&mut self, paths: &[String]) -> Result<u64> {
+        const CHUNK_SIZE: usize = 1_000;
+
+        let Some(ref connection) = self.connection else {
+            bail!("Can't delete meets without connecting to the database")
+        };
+
+        let mut deleted: u64 = meet::Entity::delete_many()
+            .filter(meet::Column::Path.is_null())
+            .exec(connection)
+            .await?
+            .rows_affected;
+
+        for paths in paths.chunks(CHUNK_SIZE) {
+            info!("Deleting {} meets", paths.len());
+            deleted += meet::Entity::delete_many()
+                .filter(meet::Column::Path.is_in(paths))
+                .exec(connection)
+                .await?
+                .rows_affected;
+        }
+
+        federation::Entity::delete_many()
+            .filter(
+                federation::Column::Code.not_in_subquery(
+                    Query::select()
+                        .column(meet::Column::Federation)
+                        .from(meet::Entity)
+                        .to_owned()
+                )
+            )
+            .exec(connection)
+            .await?;
+
+        lifter::Entity::delete_many()
+            .filter(
+                lifter::Column::Id.not_in_subquery(
+                    Query::select()
+                        .column(entry::Column::LifterId)
+                        .from(entry::Entity)
+                        .to_owned()
+                )
+            )
+            .exec(connection)
+            .await?;
+
+        Ok(deleted)
     }
 }
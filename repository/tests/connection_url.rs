@@ -0,0 +1,32 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use repository::{MigrationStatus, Repository, WriteOnlyRepository};
+
+/// `DATABASE_URL` is enough without a `.env` file, like in the deployments configured by their
+/// environment only. The test changes the environment and the working directory of the process,
+/// it is the only test of its binary
+#[tokio::test]
+async fn test_database_url_without_dotenv() {
+    let directory: PathBuf = env::temp_dir().join(format!("powerlifting_no_dotenv_{}", process::id()));
+    fs::create_dir_all(&directory).unwrap();
+    env::set_current_dir(&directory).unwrap();
+    // SAFETY: no other thread reads the environment
+    unsafe {
+        env::set_var("DATABASE_URL", format!("sqlite://{}?mode=rwc", directory.join("powerlifting.db").display()));
+        for variable in ["DATABASE_HOST", "DATABASE_NAME", "DATABASE_USERNAME", "DATABASE_PASSWORD", "DATABASE_SCHEMA"] {
+            env::remove_var(variable);
+        }
+    }
+
+    let mut repository: WriteOnlyRepository = Repository::write_only().unwrap();
+    repository.connect().await.unwrap();
+    repository.apply_migrations().await.unwrap();
+    let status: Vec<(String, MigrationStatus)> = repository.migration_status().await.unwrap();
+    repository.disconnect().await.unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert!(status.iter().all(|(_, status)| *status == MigrationStatus::Applied));
+}
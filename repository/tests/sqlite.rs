@@ -0,0 +1,357 @@
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, Statement};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use data_parsing::{Database, DivisionAliases, ImportReport, NameDisambiguation};
+use repository::{MigrationStatus, PoolOptions, ReadOnlyRepository, Repository, TestDatabase, WriteOnlyRepository};
+use types::filters::{AgeClassFilterDto, BirthYearClassFilterDto, CountryFilterDto, DivisionFilterDto, EquipmentFilterDto, EventFilterDto, FederationFilterDto, MatchModeFilterDto, QueryDto, SexFilterDto, TestedFilterDto, WeightClassFilterDto};
+use types::prelude::{ExportRow, FederationDto, MeetDto, SearchResultDto};
+
+const MEETS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../data_parsing/test_data/entries/meet_database");
+
+fn meets() -> Vec<MeetDto> {
+    let (database, _): (Database, ImportReport) = Database::from_directory(Path::new(MEETS), &DivisionAliases::default(), &NameDisambiguation::default()).unwrap();
+
+    database.iter().cloned().map(MeetDto::from).collect()
+}
+
+async fn import(repository: &mut WriteOnlyRepository) {
+    repository.connect().await.unwrap();
+    repository.apply_migrations().await.unwrap();
+
+    repository.upsert_meets(meets()).await.unwrap();
+}
+
+async fn search(mut repository: ReadOnlyRepository, powerlifters: &str, match_mode_choice: MatchModeFilterDto) -> SearchResultDto {
+    repository.connect().await.unwrap();
+    let mut result: SearchResultDto = repository.search(&query(powerlifters, match_mode_choice)).await.unwrap();
+    repository.disconnect().await.unwrap();
+
+    // The ids depend on the order of the imports
+    for entry in result.matched.iter_mut().chain(result.ambiguous.iter_mut().flat_map(|ambiguous| ambiguous.candidates.iter_mut())) {
+        entry.lifter_id = None;
+    }
+
+    result
+}
+
+fn query(powerlifters: &str, match_mode_choice: MatchModeFilterDto) -> QueryDto {
+    QueryDto {
+        federation_choice: vec![FederationFilterDto::Any],
+        equipment_choice: vec![EquipmentFilterDto::Raw],
+        sex_choice: vec![SexFilterDto::Any],
+        division_choice: vec![DivisionFilterDto::Any],
+        event_choice: EventFilterDto::SBD,
+        age_class_choice: AgeClassFilterDto::Any,
+        birth_year_class_choice: BirthYearClassFilterDto::Any,
+        tested_choice: TestedFilterDto::Any,
+        weight_class_choice: WeightClassFilterDto::Any,
+        rank_by_bodyweight: false,
+        meet_country_choice: CountryFilterDto::Any,
+        lifter_country_choice: CountryFilterDto::Any,
+        exclude_disqualified: false,
+        date_from: None,
+        date_to: None,
+        match_mode_choice,
+        powerlifters: powerlifters.to_string(),
+    }
+}
+
+fn names(result: &SearchResultDto) -> (Vec<String>, Vec<String>, Vec<(String, usize)>) {
+    (
+        result.matched.iter().map(|entry| entry.name.name.clone()).collect(),
+        result.unmatched.clone(),
+        result.ambiguous.iter().map(|ambiguous| (ambiguous.name.clone(), ambiguous.candidates.len())).collect(),
+    )
+}
+
+#[tokio::test]
+async fn test_migrations() {
+    let file: TestDatabase = TestDatabase::sqlite();
+    let mut repository: WriteOnlyRepository = Repository::write_only_at(&file.url());
+    repository.connect().await.unwrap();
+
+    repository.apply_migrations().await.unwrap();
+    let status: Vec<(String, MigrationStatus)> = repository.migration_status().await.unwrap();
+    assert!(status.iter().all(|(_, status)| *status == MigrationStatus::Applied));
+
+    repository.rollback_migrations(u32::try_from(status.len()).unwrap()).await.unwrap();
+    let status: Vec<(String, MigrationStatus)> = repository.migration_status().await.unwrap();
+    assert!(status.iter().all(|(_, status)| *status == MigrationStatus::Pending));
+
+    repository.apply_migrations().await.unwrap();
+    repository.disconnect().await.unwrap();
+}
+
+#[rstest]
+#[case("Powerlifter 1", MatchModeFilterDto::Exact, (vec!["Powerlifter 1"], vec![], vec![]))]
+#[case("powerlifter 1\nPowerlifter 1", MatchModeFilterDto::Exact, (vec!["Powerlifter 1"], vec![], vec![]))]
+#[case("Powerlifter", MatchModeFilterDto::Exact, (vec![], vec![], vec![("Powerlifter", 2)]))]
+#[case("Nobody", MatchModeFilterDto::Exact, (vec![], vec!["Nobody"], vec![]))]
+#[case("Lea Dupre O'Neil", MatchModeFilterDto::Exact, (vec!["Léa Dupré-O'Neil"], vec![], vec![]))]
+#[case("Thomas Martin #2", MatchModeFilterDto::Exact, (vec!["Thomas Martin #2"], vec![], vec![]))]
+#[case("Thomas Martin", MatchModeFilterDto::Exact, (vec![], vec![], vec![("Thomas Martin", 2)]))]
+#[case("Иван Петров", MatchModeFilterDto::Exact, (vec!["Ivan Petrov"], vec![], vec![]))]
+#[case("Powerlifer 1", MatchModeFilterDto::Exact, (vec![], vec!["Powerlifer 1"], vec![]))]
+#[case("Powerlifer 1", MatchModeFilterDto::Fuzzy, (vec!["Powerlifter 1"], vec![], vec![]))]
+#[case("Ivan Petrof", MatchModeFilterDto::Fuzzy, (vec!["Ivan Petrov"], vec![], vec![]))]
+#[tokio::test]
+async fn test_search(
+    #[case] powerlifters: &str,
+    #[case] match_mode_choice: MatchModeFilterDto,
+    #[case] expected: (Vec<&str>, Vec<&str>, Vec<(&str, usize)>),
+) {
+    let file: TestDatabase = TestDatabase::sqlite();
+    let mut repository: WriteOnlyRepository = Repository::write_only_at(&file.url());
+    import(&mut repository).await;
+    repository.disconnect().await.unwrap();
+
+    let result: SearchResultDto = search(Repository::read_only_at(&file.url(), &PoolOptions::default()), powerlifters, match_mode_choice).await;
+
+    let expected = (
+        expected.0.into_iter().map(str::to_string).collect(),
+        expected.1.into_iter().map(str::to_string).collect(),
+        expected.2.into_iter().map(|(name, candidates)| (name.to_string(), candidates)).collect(),
+    );
+    assert_eq!(expected, names(&result));
+}
+
+#[rstest]
+#[case(1)]
+#[case(WriteOnlyRepository::DEFAULT_BATCH_SIZE)]
+#[tokio::test]
+async fn test_reimport(#[case] batch_size: usize) {
+    let file: TestDatabase = TestDatabase::sqlite();
+    let mut repository: WriteOnlyRepository = Repository::write_only_at(&file.url()).with_batch_size(batch_size);
+    import(&mut repository).await;
+    let first: SearchResultDto = search(Repository::read_only_at(&file.url(), &PoolOptions::default()), "Powerlifter 1\nThomas Martin", MatchModeFilterDto::Exact).await;
+
+    for meet in meets() {
+        repository.upsert_meet(meet).await.unwrap();
+    }
+    let single: SearchResultDto = search(Repository::read_only_at(&file.url(), &PoolOptions::default()), "Powerlifter 1\nThomas Martin", MatchModeFilterDto::Exact).await;
+    assert_eq!(first, single);
+
+    repository.upsert_meets(meets()).await.unwrap();
+    let second: SearchResultDto = search(Repository::read_only_at(&file.url(), &PoolOptions::default()), "Powerlifter 1\nThomas Martin", MatchModeFilterDto::Exact).await;
+    assert_eq!(first, second);
+
+    let paths: Vec<String> = meets().into_iter().map(|meet| meet.path).collect();
+    assert_eq!(u64::try_from(paths.len()).unwrap(), repository.delete_meets(&paths).await.unwrap());
+    repository.disconnect().await.unwrap();
+
+    let mut read_only: ReadOnlyRepository = Repository::read_only_at(&file.url(), &PoolOptions::default());
+    read_only.connect().await.unwrap();
+    assert_eq!(Vec::<FederationDto>::new(), read_only.federations().await.unwrap());
+    read_only.disconnect().await.unwrap();
+
+    let result: SearchResultDto = search(Repository::read_only_at(&file.url(), &PoolOptions::default()), "Powerlifter 1", MatchModeFilterDto::Exact).await;
+    assert_eq!(vec!["Powerlifter 1".to_string()], result.unmatched);
+}
+
+#[rstest]
+#[case("Powerlifter 1", "105")]
+#[case("Powerlifter 2", "84")]
+#[case("Thomas Martin #1", "74")]
+#[case("Heavy Lifter", "120+")]
+#[tokio::test]
+async fn test_weight_class(#[case] powerlifters: &str, #[case] expected: &str) {
+    let file: TestDatabase = TestDatabase::sqlite();
+    let mut repository: WriteOnlyRepository = Repository::write_only_at(&file.url());
+    import(&mut repository).await;
+    repository.disconnect().await.unwrap();
+
+    let result: SearchResultDto = search(Repository::read_only_at(&file.url(), &PoolOptions::default()), powerlifters, MatchModeFilterDto::Exact).await;
+
+    let rows: Vec<String> = result.matched.into_iter().map(|entry| ExportRow::from(entry).weight_class).collect();
+    assert_eq!(vec![expected.to_string()], rows);
+}
+
+#[rstest]
+#[case("IPF/M/74", false, "Teen Lifter\nTaro Yamada", (vec!["Teen Lifter"], vec!["Taro Yamada"], vec![]))]
+#[case("IPF/M/120+", false, "Heavy Lifter\nPowerlifter 1", (vec!["Heavy Lifter"], vec!["Powerlifter 1"], vec![]))]
+#[case("IPF/F/84", false, "Powerlifter 2\nLea Dupre", (vec!["Powerlifter 2"], vec!["Lea Dupre"], vec![]))]
+#[case("IPF/M/66", true, "Taro Yamada\nOpen Lifter", (vec!["Taro Yamada"], vec!["Open Lifter"], vec![]))]
+#[case("IPF/M/74", true, "Thomas Martin\nIvan Petrov", (vec![], vec!["Ivan Petrov"], vec![("Thomas Martin", 2)]))]
+#[case("Pre-2011/M/125+", true, "Heavy Lifter", (vec!["Heavy Lifter"], vec![], vec![]))]
+#[tokio::test]
+async fn test_weight_class_filter(
+    #[case] weight_class_choice: &str,
+    #[case] rank_by_bodyweight: bool,
+    #[case] powerlifters: &str,
+    #[case] expected: (Vec<&str>, Vec<&str>, Vec<(&str, usize)>),
+) {
+    let file: TestDatabase = TestDatabase::sqlite();
+    let mut repository: WriteOnlyRepository = Repository::write_only_at(&file.url());
+    import(&mut repository).await;
+    repository.disconnect().await.unwrap();
+
+    let mut read_only: ReadOnlyRepository = Repository::read_only_at(&file.url(), &PoolOptions::default());
+    read_only.connect().await.unwrap();
+    let mut query: QueryDto = query(powerlifters, MatchModeFilterDto::Exact);
+    query.weight_class_choice = weight_class_choice.parse().unwrap();
+    query.rank_by_bodyweight = rank_by_bodyweight;
+    let result: SearchResultDto = read_only.search(&query).await.unwrap();
+    read_only.disconnect().await.unwrap();
+
+    let expected = (
+        expected.0.into_iter().map(str::to_string).collect(),
+        expected.1.into_iter().map(str::to_string).collect(),
+        expected.2.into_iter().map(|(name, candidates)| (name.to_string(), candidates)).collect(),
+    );
+    assert_eq!(expected, names(&result));
+}
+
+async fn filtered(url: &str, filter: fn(&mut QueryDto)) -> SearchResultDto {
+    let mut read_only: ReadOnlyRepository = Repository::read_only_at(url, &PoolOptions::default());
+    read_only.connect().await.unwrap();
+    let mut query: QueryDto = query("Powerlifter 1\nPowerlifter 2", MatchModeFilterDto::Exact);
+    filter(&mut query);
+    let mut result: SearchResultDto = read_only.search(&query).await.unwrap();
+    read_only.disconnect().await.unwrap();
+
+    // The ids depend on the order of the imports
+    for entry in &mut result.matched {
+        entry.lifter_id = None;
+    }
+
+    result
+}
+
+/// The filters used to be compared to their lowercased labels, like "men", "masters 3" or
+/// "single-ply", which are not values of the enums
+#[rstest]
+#[case(|query: &mut QueryDto| query.sex_choice = vec![SexFilterDto::M], (vec!["Powerlifter 1"], vec!["Powerlifter 2"], vec![]))]
+#[case(|query: &mut QueryDto| query.division_choice = vec![DivisionFilterDto::Masters3], (vec!["Powerlifter 1"], vec!["Powerlifter 2"], vec![]))]
+#[case(|query: &mut QueryDto| query.equipment_choice = vec![EquipmentFilterDto::Single], (vec![], vec!["Powerlifter 1", "Powerlifter 2"], vec![]))]
+#[tokio::test]
+async fn test_filters(
+    #[case] filter: fn(&mut QueryDto),
+    #[case] expected: (Vec<&str>, Vec<&str>, Vec<(&str, usize)>),
+) {
+    let file: TestDatabase = TestDatabase::sqlite();
+    let mut repository: WriteOnlyRepository = Repository::write_only_at(&file.url());
+    import(&mut repository).await;
+    repository.disconnect().await.unwrap();
+
+    let result: SearchResultDto = filtered(&file.url(), filter).await;
+
+    let expected = (
+        expected.0.into_iter().map(str::to_string).collect(),
+        expected.1.into_iter().map(str::to_string).collect(),
+        expected.2.into_iter().map(|(name, candidates)| (name.to_string(), candidates)).collect(),
+    );
+    assert_eq!(expected, names(&result));
+}
+
+/// Columns of the tables as (table, column, type, nullable), in the spelling of sqlite
+type Columns = BTreeSet<(String, String, String, bool)>;
+
+async fn columns(url: &str) -> Columns {
+    let connection: DatabaseConnection = sea_orm::Database::connect(url).await.unwrap();
+    let backend: DbBackend = connection.get_database_backend();
+    let sql: &str = match backend {
+        DbBackend::Sqlite => r#"
+            SELECT tables.name AS table_name, columns.name AS column_name, lower(columns.type) AS data_type,
+                   NULL AS length, NULL AS precision, NULL AS scale, columns."notnull" = 0 AS nullable
+            FROM sqlite_master AS tables JOIN pragma_table_info(tables.name) AS columns
+            WHERE tables.type = 'table' AND tables.name <> 'seaql_migrations' AND tables.name NOT LIKE 'sqlite_%'
+        "#,
+        _ => r"
+            SELECT table_name::text, column_name::text, data_type::text, character_maximum_length::int AS length,
+                   numeric_precision::int AS precision, numeric_scale::int AS scale, is_nullable = 'YES' AS nullable
+            FROM information_schema.columns
+            WHERE table_schema = current_schema() AND table_name <> 'seaql_migrations'
+        ",
+    };
+
+    let columns: Columns = connection
+        .query_all(Statement::from_string(backend, sql))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| {
+            let data_type: String = row.try_get("", "data_type").unwrap();
+            let length: Option<i32> = row.try_get("", "length").unwrap();
+            let precision: Option<i32> = row.try_get("", "precision").unwrap();
+            let scale: Option<i32> = row.try_get("", "scale").unwrap();
+            // Sqlite stores the enums as strings, the decimals as reals and the dates as text
+            let data_type: String = match data_type.as_str() {
+                "character varying" => format!("varchar({})", length.unwrap()),
+                "USER-DEFINED" => "varchar(32)".to_string(),
+                "numeric" => format!("real({}, {})", precision.unwrap(), scale.unwrap()),
+                "date" => "date_text".to_string(),
+                _ => data_type,
+            };
+
+            (row.try_get("", "table_name").unwrap(), row.try_get("", "column_name").unwrap(), data_type, row.try_get("", "nullable").unwrap())
+        })
+        .collect();
+    connection.close().await.unwrap();
+
+    columns
+}
+
+/// `cargo test -p repository --test sqlite -- --ignored`, the Postgres databases are created on
+/// the server of `TEST_DATABASE_URL` and dropped afterwards
+#[rstest]
+#[case("Powerlifter 1\nPowerlifter\nThomas Martin #1\nNobody", MatchModeFilterDto::Exact)]
+#[case("Powerlifer 1\nIvan Petrof\nTaro Yamada", MatchModeFilterDto::Fuzzy)]
+#[tokio::test]
+#[ignore = "needs a Postgres server, a database is created on it"]
+async fn test_postgres_parity(#[case] powerlifters: &str, #[case] match_mode_choice: MatchModeFilterDto) {
+    let file: TestDatabase = TestDatabase::sqlite();
+    let mut sqlite: WriteOnlyRepository = Repository::write_only_at(&file.url());
+    import(&mut sqlite).await;
+    sqlite.disconnect().await.unwrap();
+    let database: TestDatabase = TestDatabase::create().await.unwrap();
+    let mut postgres: WriteOnlyRepository = Repository::write_only_at(&database.url());
+    import(&mut postgres).await;
+    postgres.disconnect().await.unwrap();
+
+    assert_eq!(
+        search(Repository::read_only_at(&database.url(), &PoolOptions::default()), powerlifters, match_mode_choice).await,
+        search(Repository::read_only_at(&file.url(), &PoolOptions::default()), powerlifters, match_mode_choice).await,
+    );
+}
+
+/// Postgres rejects the values which are not in its enums, where sqlite just matches nothing
+#[rstest]
+#[case(|query: &mut QueryDto| query.sex_choice = vec![SexFilterDto::M])]
+#[case(|query: &mut QueryDto| query.division_choice = vec![DivisionFilterDto::Masters3])]
+#[case(|query: &mut QueryDto| query.equipment_choice = vec![EquipmentFilterDto::Single])]
+#[tokio::test]
+#[ignore = "needs a Postgres server, a database is created on it"]
+async fn test_postgres_filters(#[case] filter: fn(&mut QueryDto)) {
+    let file: TestDatabase = TestDatabase::sqlite();
+    let mut sqlite: WriteOnlyRepository = Repository::write_only_at(&file.url());
+    import(&mut sqlite).await;
+    sqlite.disconnect().await.unwrap();
+    let database: TestDatabase = TestDatabase::create().await.unwrap();
+    let mut postgres: WriteOnlyRepository = Repository::write_only_at(&database.url());
+    import(&mut postgres).await;
+    postgres.disconnect().await.unwrap();
+
+    assert_eq!(filtered(&database.url(), filter).await, filtered(&file.url(), filter).await);
+}
+
+/// The sqlite schema is created at once rather than by the Postgres migrations, both must end
+/// up with the same columns
+#[tokio::test]
+#[ignore = "needs a Postgres server, a database is created on it"]
+async fn test_postgres_schema() {
+    let file: TestDatabase = TestDatabase::sqlite();
+    let mut sqlite: WriteOnlyRepository = Repository::write_only_at(&file.url());
+    sqlite.connect().await.unwrap();
+    sqlite.apply_migrations().await.unwrap();
+    sqlite.disconnect().await.unwrap();
+    let database: TestDatabase = TestDatabase::create().await.unwrap();
+    let mut postgres: WriteOnlyRepository = Repository::write_only_at(&database.url());
+    postgres.connect().await.unwrap();
+    postgres.apply_migrations().await.unwrap();
+    postgres.disconnect().await.unwrap();
+
+    assert_eq!(columns(&database.url()).await, columns(&file.url()).await);
+}
@@ -0,0 +1,107 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use data_parsing::Database;
+use std::collections::HashMap;
+use types::filters::QueryDto;
+use types::prelude::{CandidateDto, EntryDto, FederationDto, MeetDataDto, MeetDto, SearchResultDto};
+
+use crate::search_backend::SearchBackend;
+
+/// Entry held in memory, with the columns the repository computes on import
+#[derive(Debug)]
+struct IndexedEntry {
+    meet: usize,
+    entry: EntryDto,
+    name_normalized: String,
+}
+
+/// Searches the meets of a database held in memory, ranked like the repository ranks them.
+/// The entries are numbered in the order of the database, like the repository numbers them
+/// when the database is imported into an empty schema
+#[derive(Debug)]
+pub struct InMemoryBackend {
+    meets: Vec<MeetDataDto>,
+    entries: Vec<IndexedEntry>,
+    /// Entries of each lifter, in the order of their ids
+    lifters: Vec<Vec<usize>>,
+    federations: Vec<FederationDto>,
+}
+
+impl InMemoryBackend {
+    #[must_use]
+    pub fn new(database: &Database) -> Self {
+        let mut meets: Vec<MeetDataDto> = Vec::with_capacity(database.len());
+        let mut entries: Vec<IndexedEntry> = Vec::new();
+        let mut lifters: Vec<Vec<usize>> = Vec::new();
+        let mut lifter_ids: HashMap<String, usize> = HashMap::new();
+
+        for meet in database.iter().cloned().map(MeetDto::from) {
+            for mut entry in meet.entries {
+                let lifter: usize = *lifter_ids.entry(entry.name.name.clone()).or_insert_with(|| {
+                    lifters.push(Vec::new());
+                    lifters.len() - 1
+                });
+                lifters[lifter].push(entries.len());
+                entry.lifter_id = i32::try_from(lifter + 1).ok();
+
+                entries.push(IndexedEntry {
+                    meet: meets.len(),
+                    name_normalized: entry.name_normalized(),
+                    entry,
+                });
+            }
+            meets.push(meet.data);
+        }
+
+        let mut federations: Vec<FederationDto> = meets.iter().map(|meet| meet.federation.clone()).collect();
+        federations.sort();
+        federations.dedup();
+
+        Self {
+            meets,
+            entries,
+            lifters,
+            federations,
+        }
+    }
+
+    /// Entry of the lifter passing the filters with the best total, the lowest id wins a tie
+    fn best_entry(&self, query: &QueryDto, lifter: &[usize]) -> Option<(usize, &IndexedEntry)> {
+        lifter
+            .iter()
+            .map(|&id| (id, &self.entries[id]))
+            .filter(|(_, entry)| query.matches(&self.meets[entry.meet], &entry.entry))
+            .reduce(|best, entry| if entry.1.entry.total > best.1.entry.total { entry } else { best })
+    }
+}
+
+#[async_trait]
+impl SearchBackend for InMemoryBackend {
+    async fn federations(&self) -> Result<Vec<FederationDto>> {
+        Ok(self.federations.clone())
+    }
+
+    async fn search(&self, query: &QueryDto) -> Result<SearchResultDto> {
+        let mut best_entries: Vec<(usize, &IndexedEntry)> = self.lifters
+            .iter()
+            .filter_map(|lifter| self.best_entry(query, lifter))
+            .collect();
+        best_entries.sort_by(|(left_id, left), (right_id, right)| {
+            right.entry.total
+                .cmp(&left.entry.total)
+                .then_with(|| left_id.cmp(right_id))
+        });
+
+        let candidates: Vec<CandidateDto> = best_entries
+            .into_iter()
+            .zip(1..)
+            .map(|((_, entry), rank)| CandidateDto {
+                rank,
+                entry: &entry.entry,
+                name_normalized: &entry.name_normalized,
+            })
+            .collect();
+
+        Ok(SearchResultDto::from_candidates(&query.powerlifters, query.match_mode_choice, &candidates))
+    }
+}
@@ -1 +1,4 @@
+pub mod in_memory_backend;
+pub mod search_backend;
 pub mod search_engine;
+pub mod search_error;
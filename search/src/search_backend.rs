@@ -0,0 +1,36 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use repository::ReadOnlyRepository;
+use std::fmt::Debug;
+use types::filters::QueryDto;
+use types::prelude::{FederationDto, SearchResultDto};
+
+/// Storage the lifters are searched in
+#[async_trait]
+pub trait SearchBackend: Debug + Send + Sync {
+    /// Federations of the imported meets, sorted by code
+    async fn federations(&self) -> Result<Vec<FederationDto>>;
+
+    /// Ranks the lifters matching the requested names of the query
+    async fn search(&self, query: &QueryDto) -> Result<SearchResultDto>;
+
+    /// Whether the storage can be reached, asked when a search timed out
+    async fn is_reachable(&self) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+impl SearchBackend for ReadOnlyRepository {
+    async fn federations(&self) -> Result<Vec<FederationDto>> {
+        Self::federations(self).await
+    }
+
+    async fn search(&self, query: &QueryDto) -> Result<SearchResultDto> {
+        Self::search(self, query).await
+    }
+
+    async fn is_reachable(&self) -> bool {
+        Self::is_reachable(self).await
+    }
+}
@@ -1,22 +1,44 @@
-use repository::{ReadOnlyRepository, Repository};
+use anyhow::Error;
+use std::sync::Arc;
 use types::filters::QueryDto;
-use types::prelude::{EntryDto, ExportRow};
+use types::prelude::{ExportResult, FederationDto};
 
+use crate::search_backend::SearchBackend;
+use crate::search_error::SearchError;
+
+/// Searches the lifters through a backend connected once and shared by the requests
 #[derive(Debug, Clone)]
-pub struct SearchEngine;
+pub struct SearchEngine {
+    backend: Arc<dyn SearchBackend>,
+}
 
 impl SearchEngine {
-    pub async fn search(&self, query: &QueryDto) -> Vec<ExportRow> {
-        let mut repository: ReadOnlyRepository = Repository::read_only().unwrap();
-        repository.connect().await.unwrap();
-        let result: Vec<EntryDto> = repository.search(query).await.unwrap();
-        repository.disconnect().await.unwrap();
+    pub fn new(backend: Arc<dyn SearchBackend>) -> Self {
+        Self {
+            backend,
+        }
+    }
 
-        let result: Vec<ExportRow> = result
-            .into_iter()
-            .map(ExportRow::from)
-            .collect();
+    pub async fn federations(&self) -> Result<Vec<FederationDto>, SearchError> {
+        match self.backend.federations().await {
+            Ok(federations) => Ok(federations),
+            Err(e) => Err(self.search_error(e).await),
+        }
+    }
+
+    pub async fn search(&self, query: &QueryDto) -> Result<ExportResult, SearchError> {
+        match self.backend.search(query).await {
+            Ok(result) => Ok(ExportResult::from(result)),
+            Err(e) => Err(self.search_error(e).await),
+        }
+    }
 
-        result
+    /// A stopped database makes the pool time out acquiring a connection, the timeouts of an
+    /// unreachable backend are connection errors
+    async fn search_error(&self, error: Error) -> SearchError {
+        match SearchError::from(error) {
+            SearchError::Timeout(e) if !self.backend.is_reachable().await => SearchError::Connection(e),
+            error => error,
+        }
     }
 }
@@ -0,0 +1,216 @@
+use anyhow::Error;
+use sea_orm::sqlx;
+use sea_orm::{ConnAcquireErr, DbErr, RuntimeErr};
+use std::fmt::{self, Display};
+
+/// Postgres codes of the errors raised when the server is not accepting queries, like during a
+/// restart
+const CONNECTION_ERROR_CODES: [&str; 3] = ["57P01", "57P02", "57P03"];
+/// Class of the postgres connection errors
+const CONNECTION_ERROR_CLASS: &str = "08";
+/// Postgres code of the queries cancelled by a statement timeout
+const QUERY_CANCELED_CODE: &str = "57014";
+
+/// Reason why a search failed
+#[derive(Debug)]
+pub enum SearchError {
+    /// The repository is not usable, like when it isn't connected
+    Configuration(Error),
+    /// The database can't be reached
+    Connection(Error),
+    /// The database rejected the query
+    Query(Error),
+    /// The database didn't answer in time
+    Timeout(Error),
+}
+
+/// Variant of the error, decided before the error is moved into it
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Kind {
+    Configuration,
+    Connection,
+    Query,
+    Timeout,
+}
+
+impl Kind {
+    fn of_sqlx(error: &sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::PoolTimedOut => Self::Timeout,
+            sqlx::Error::Io(_) | sqlx::Error::Tls(_) | sqlx::Error::PoolClosed | sqlx::Error::WorkerCrashed => Self::Connection,
+            sqlx::Error::Database(e) => match e.code().as_deref() {
+                Some(QUERY_CANCELED_CODE) => Self::Timeout,
+                Some(code) if code.starts_with(CONNECTION_ERROR_CLASS) || CONNECTION_ERROR_CODES.contains(&code) => Self::Connection,
+                _ => Self::Query,
+            },
+            _ => Self::Query,
+        }
+    }
+
+    /// The repository raises database errors, any other error comes from its setup
+    fn of(error: &Error) -> Self {
+        match error.chain().find_map(|e| e.downcast_ref::<DbErr>()) {
+            None => Self::Configuration,
+            Some(DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)) => Self::Timeout,
+            Some(DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed)) => Self::Connection,
+            Some(DbErr::Conn(RuntimeErr::SqlxError(e))) if Self::of_sqlx(e) == Self::Timeout => Self::Timeout,
+            Some(DbErr::Conn(_)) => Self::Connection,
+            Some(DbErr::Query(RuntimeErr::SqlxError(e)) | DbErr::Exec(RuntimeErr::SqlxError(e))) => Self::of_sqlx(e),
+            Some(_) => Self::Query,
+        }
+    }
+}
+
+impl SearchError {
+    pub const fn source_error(&self) -> &Error {
+        match self {
+            Self::Configuration(e) | Self::Connection(e) | Self::Query(e) | Self::Timeout(e) => e,
+        }
+    }
+}
+
+impl From<Error> for SearchError {
+    fn from(value: Error) -> Self {
+        match Kind::of(&value) {
+            Kind::Configuration => Self::Configuration(value),
+            Kind::Connection => Self::Connection(value),
+            Kind::Query => Self::Query(value),
+            Kind::Timeout => Self::Timeout(value),
+        }
+    }
+}
+
+impl Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Configuration(e) => write!(f, "search engine misconfigured: {e:#}"),
+            Self::Connection(e) => write!(f, "database unreachable: {e:#}"),
+            Self::Query(e) => write!(f, "query failed: {e:#}"),
+            Self::Timeout(e) => write!(f, "database timed out: {e:#}"),
+        }
+    }
+}
+
+impl std::error::Error for SearchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source_error().as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::{anyhow, Error};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use sea_orm::sqlx;
+    use sea_orm::sqlx::error::{DatabaseError, ErrorKind};
+    use sea_orm::{ConnAcquireErr, DbErr, RuntimeErr};
+    use std::borrow::Cow;
+    use std::fmt::{self, Display};
+    use std::io;
+
+    use super::Kind;
+
+    /// Database error carrying only a code, like the ones postgres raises
+    #[derive(Debug)]
+    struct CodedError(&'static str);
+
+    impl Display for CodedError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "error {}", self.0)
+        }
+    }
+
+    impl std::error::Error for CodedError {}
+
+    impl DatabaseError for CodedError {
+        fn message(&self) -> &str {
+            self.0
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.0))
+        }
+
+        fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    fn database(code: &'static str) -> sqlx::Error {
+        sqlx::Error::Database(Box::new(CodedError(code)))
+    }
+
+    fn io(kind: io::ErrorKind) -> sqlx::Error {
+        sqlx::Error::Io(io::Error::from(kind))
+    }
+
+    #[rstest]
+    #[case(sqlx::Error::PoolTimedOut, Kind::Timeout)]
+    #[case(sqlx::Error::PoolClosed, Kind::Connection)]
+    #[case(sqlx::Error::WorkerCrashed, Kind::Connection)]
+    #[case(sqlx::Error::Tls("handshake failed".into()), Kind::Connection)]
+    #[case(io(io::ErrorKind::ConnectionRefused), Kind::Connection)]
+    #[case(io(io::ErrorKind::ConnectionReset), Kind::Connection)]
+    #[case(io(io::ErrorKind::ConnectionAborted), Kind::Connection)]
+    #[case(io(io::ErrorKind::BrokenPipe), Kind::Connection)]
+    #[case(io(io::ErrorKind::TimedOut), Kind::Connection)]
+    #[case(io(io::ErrorKind::UnexpectedEof), Kind::Connection)]
+    #[case(database("57014"), Kind::Timeout)]
+    #[case(database("57P01"), Kind::Connection)]
+    #[case(database("57P02"), Kind::Connection)]
+    #[case(database("57P03"), Kind::Connection)]
+    #[case(database("08000"), Kind::Connection)]
+    #[case(database("08006"), Kind::Connection)]
+    #[case(database("42P01"), Kind::Query)]
+    #[case(database("57000"), Kind::Query)]
+    #[case(sqlx::Error::RowNotFound, Kind::Query)]
+    #[case(sqlx::Error::Protocol("unexpected message".to_string()), Kind::Query)]
+    #[case(sqlx::Error::ColumnNotFound("name".to_string()), Kind::Query)]
+    #[case(sqlx::Error::Decode("invalid utf-8".into()), Kind::Query)]
+    #[case(sqlx::Error::Configuration("invalid url".into()), Kind::Query)]
+    fn test_of_sqlx(
+        #[case] error: sqlx::Error,
+        #[case] expected: Kind,
+    ) {
+        assert_eq!(expected, Kind::of_sqlx(&error));
+    }
+
+    #[rstest]
+    #[case(anyhow!("missing database url"), Kind::Configuration)]
+    #[case(Error::from(io::Error::from(io::ErrorKind::NotFound)), Kind::Configuration)]
+    #[case(Error::from(DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)), Kind::Timeout)]
+    #[case(Error::from(DbErr::ConnectionAcquire(ConnAcquireErr::ConnectionClosed)), Kind::Connection)]
+    #[case(Error::from(DbErr::Conn(RuntimeErr::SqlxError(sqlx::Error::PoolTimedOut))), Kind::Timeout)]
+    #[case(Error::from(DbErr::Conn(RuntimeErr::SqlxError(database("57014")))), Kind::Timeout)]
+    #[case(Error::from(DbErr::Conn(RuntimeErr::SqlxError(io(io::ErrorKind::ConnectionRefused)))), Kind::Connection)]
+    #[case(Error::from(DbErr::Conn(RuntimeErr::SqlxError(sqlx::Error::RowNotFound))), Kind::Connection)]
+    #[case(Error::from(DbErr::Conn(RuntimeErr::Internal("closed".to_string()))), Kind::Connection)]
+    #[case(Error::from(DbErr::Query(RuntimeErr::SqlxError(database("57014")))), Kind::Timeout)]
+    #[case(Error::from(DbErr::Query(RuntimeErr::SqlxError(database("08006")))), Kind::Connection)]
+    #[case(Error::from(DbErr::Query(RuntimeErr::SqlxError(io(io::ErrorKind::ConnectionReset)))), Kind::Connection)]
+    #[case(Error::from(DbErr::Query(RuntimeErr::SqlxError(database("42P01")))), Kind::Query)]
+    #[case(Error::from(DbErr::Exec(RuntimeErr::SqlxError(database("57P01")))), Kind::Connection)]
+    #[case(Error::from(DbErr::Exec(RuntimeErr::SqlxError(sqlx::Error::PoolTimedOut))), Kind::Timeout)]
+    #[case(Error::from(DbErr::Query(RuntimeErr::Internal("invalid query".to_string()))), Kind::Query)]
+    #[case(Error::from(DbErr::RecordNotFound("entries".to_string())), Kind::Query)]
+    #[case(Error::from(DbErr::Query(RuntimeErr::SqlxError(database("57014")))).context("cannot search"), Kind::Timeout)]
+    fn test_of(
+        #[case] error: Error,
+        #[case] expected: Kind,
+    ) {
+        assert_eq!(expected, Kind::of(&error));
+    }
+}
@@ -0,0 +1,160 @@
+use chrono::NaiveDate;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use std::path::Path;
+
+use data_parsing::{Database, DivisionAliases, ImportReport, NameDisambiguation};
+use repository::{PoolOptions, ReadOnlyRepository, Repository, TestDatabase, WriteOnlyRepository};
+use search::in_memory_backend::InMemoryBackend;
+use search::search_backend::SearchBackend;
+use types::filters::{AgeClassFilterDto, BirthYearClassFilterDto, CountryFilterDto, DivisionFilterDto, EquipmentFilterDto, EventFilterDto, FederationFilterDto, MatchModeFilterDto, QueryDto, SexFilterDto, TestedFilterDto, WeightClassFilterDto};
+use types::prelude::{CountryDto, DateDto, FederationDto, MeetDto, SearchResultDto};
+
+const MEETS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../data_parsing/test_data/entries/meet_database");
+
+const POWERLIFTERS: &str = "Powerlifter 1\nPowerlifter 2\nPowerlifter\nThomas Martin\nThomas Martin #2\nLea Dupre\nIvan Petrov\nTaro Yamada\nHeavy Lifter\nTeen Lifter\nOpen Lifter\nFirstName LastName\nNobody";
+const FUZZY_POWERLIFTERS: &str = "Powerlifer 1\nThomas Martn\nLea Dupree\nIvan Petrof\nTaro Yamad\nNobody";
+
+fn database() -> Database {
+    let (database, _): (Database, ImportReport) = Database::from_directory(Path::new(MEETS), &DivisionAliases::default(), &NameDisambiguation::default()).unwrap();
+
+    database
+}
+
+async fn import(mut repository: WriteOnlyRepository) {
+    repository.connect().await.unwrap();
+    repository.apply_migrations().await.unwrap();
+
+    for meet in database().iter().cloned().map(MeetDto::from) {
+        repository.upsert_meet(meet).await.unwrap();
+    }
+
+    repository.disconnect().await.unwrap();
+}
+
+async fn search(backend: &dyn SearchBackend, query: &QueryDto) -> SearchResultDto {
+    let mut result: SearchResultDto = backend.search(query).await.unwrap();
+
+    // The ids depend on the order of the imports
+    for entry in result.matched.iter_mut().chain(result.ambiguous.iter_mut().flat_map(|ambiguous| ambiguous.candidates.iter_mut())) {
+        entry.lifter_id = None;
+    }
+
+    result
+}
+
+fn query(powerlifters: &str, match_mode_choice: MatchModeFilterDto, filter: fn(&mut QueryDto)) -> QueryDto {
+    let mut query: QueryDto = QueryDto {
+        federation_choice: vec![FederationFilterDto::Any],
+        equipment_choice: vec![EquipmentFilterDto::Raw],
+        sex_choice: vec![SexFilterDto::Any],
+        division_choice: vec![DivisionFilterDto::Any],
+        event_choice: EventFilterDto::SBD,
+        age_class_choice: AgeClassFilterDto::Any,
+        birth_year_class_choice: BirthYearClassFilterDto::Any,
+        tested_choice: TestedFilterDto::Any,
+        weight_class_choice: WeightClassFilterDto::Any,
+        rank_by_bodyweight: false,
+        meet_country_choice: CountryFilterDto::Any,
+        lifter_country_choice: CountryFilterDto::Any,
+        exclude_disqualified: false,
+        date_from: None,
+        date_to: None,
+        match_mode_choice,
+        powerlifters: powerlifters.to_string(),
+    };
+    filter(&mut query);
+
+    query
+}
+
+fn date(year: i32, month: u32, day: u32) -> Option<DateDto> {
+    NaiveDate::from_ymd_opt(year, month, day).map(DateDto::from)
+}
+
+fn federation(code: &str) -> FederationFilterDto {
+    FederationFilterDto::Federation(FederationDto::from(code.to_string()))
+}
+
+fn class(value: &str) -> WeightClassFilterDto {
+    value.parse().unwrap()
+}
+
+#[rstest]
+#[case(MatchModeFilterDto::Exact, |_: &mut QueryDto| {})]
+#[case(MatchModeFilterDto::Fuzzy, |_: &mut QueryDto| {})]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.sex_choice = vec![SexFilterDto::F])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.division_choice = vec![DivisionFilterDto::Open])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.division_choice = vec![DivisionFilterDto::Juniors])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.age_class_choice = AgeClassFilterDto::Age20To23)]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.federation_choice = vec![federation("USAPL")])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.meet_country_choice = CountryFilterDto::Country(CountryDto::Germany))]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.tested_choice = TestedFilterDto::Untested)]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.exclude_disqualified = true)]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.date_from = date(2024, 4, 1))]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.date_to = date(2024, 4, 1))]
+#[case(MatchModeFilterDto::Fuzzy, |query: &mut QueryDto| query.sex_choice = vec![SexFilterDto::M])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.federation_choice = vec![federation("USAPL"), federation("IPF")])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.division_choice = vec![DivisionFilterDto::Open, DivisionFilterDto::Juniors])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.sex_choice = vec![SexFilterDto::F, SexFilterDto::M])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.equipment_choice = vec![EquipmentFilterDto::Wraps, EquipmentFilterDto::Raw])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.equipment_choice = vec![EquipmentFilterDto::Wraps])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.equipment_choice = vec![])]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.weight_class_choice = class("IPF/M/74"))]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.weight_class_choice = class("IPF/M/120+"))]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| query.weight_class_choice = class("IPF 2011-2020/F/84"))]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| {
+    query.weight_class_choice = class("IPF/M/66");
+    query.rank_by_bodyweight = true;
+})]
+#[case(MatchModeFilterDto::Exact, |query: &mut QueryDto| {
+    query.weight_class_choice = class("Pre-2011/M/125+");
+    query.rank_by_bodyweight = true;
+})]
+#[tokio::test]
+async fn test_sqlite_parity(#[case] match_mode_choice: MatchModeFilterDto, #[case] filter: fn(&mut QueryDto)) {
+    let file: TestDatabase = TestDatabase::sqlite();
+    import(Repository::write_only_at(&file.url())).await;
+    let mut repository: ReadOnlyRepository = Repository::read_only_at(&file.url(), &PoolOptions::default());
+    repository.connect().await.unwrap();
+    let in_memory: InMemoryBackend = InMemoryBackend::new(&database());
+
+    let powerlifters: &str = match match_mode_choice {
+        MatchModeFilterDto::Exact => POWERLIFTERS,
+        MatchModeFilterDto::Fuzzy => FUZZY_POWERLIFTERS,
+    };
+    let query: QueryDto = query(powerlifters, match_mode_choice, filter);
+
+    assert_eq!(
+        search(&repository, &query).await,
+        search(&in_memory, &query).await,
+    );
+    assert_eq!(
+        SearchBackend::federations(&repository).await.unwrap(),
+        in_memory.federations().await.unwrap(),
+    );
+    repository.disconnect().await.unwrap();
+}
+
+/// `cargo test -p search --test in_memory_backend -- --ignored`, the Postgres database is
+/// created on the server of `TEST_DATABASE_URL` and dropped afterwards
+#[rstest]
+#[case(MatchModeFilterDto::Exact, POWERLIFTERS)]
+#[case(MatchModeFilterDto::Fuzzy, FUZZY_POWERLIFTERS)]
+#[tokio::test]
+#[ignore = "needs a Postgres server, a database is created on it"]
+async fn test_postgres_parity(#[case] match_mode_choice: MatchModeFilterDto, #[case] powerlifters: &str) {
+    let test_database: TestDatabase = TestDatabase::create().await.unwrap();
+    import(Repository::write_only_at(&test_database.url())).await;
+    let mut repository: ReadOnlyRepository = Repository::read_only_at(&test_database.url(), &PoolOptions::default());
+    repository.connect().await.unwrap();
+    let in_memory: InMemoryBackend = InMemoryBackend::new(&database());
+
+    let query: QueryDto = query(powerlifters, match_mode_choice, |_| {});
+
+    assert_eq!(
+        search(&repository, &query).await,
+        search(&in_memory, &query).await,
+    );
+    repository.disconnect().await.unwrap();
+}
@@ -0,0 +1,53 @@
+use strum_macros::Display;
+
+/// Age range of a lifter at the date of a meet
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum AgeClassDto {
+    #[strum(to_string = "5-12")]
+    Age5To12,
+
+    #[strum(to_string = "13-15")]
+    Age13To15,
+
+    #[strum(to_string = "16-17")]
+    Age16To17,
+
+    #[strum(to_string = "18-19")]
+    Age18To19,
+
+    #[strum(to_string = "20-23")]
+    Age20To23,
+
+    #[strum(to_string = "24-34")]
+    Age24To34,
+
+    #[strum(to_string = "35-39")]
+    Age35To39,
+
+    #[strum(to_string = "40-44")]
+    Age40To44,
+
+    #[strum(to_string = "45-49")]
+    Age45To49,
+
+    #[strum(to_string = "50-54")]
+    Age50To54,
+
+    #[strum(to_string = "55-59")]
+    Age55To59,
+
+    #[strum(to_string = "60-64")]
+    Age60To64,
+
+    #[strum(to_string = "65-69")]
+    Age65To69,
+
+    #[strum(to_string = "70-74")]
+    Age70To74,
+
+    #[strum(to_string = "75-79")]
+    Age75To79,
+
+    #[strum(to_string = "80-999")]
+    Age80To999,
+}
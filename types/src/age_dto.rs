@@ -0,0 +1,23 @@
+use rust_decimal::Decimal;
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AgeDto(pub Decimal);
+
+impl From<Decimal> for AgeDto {
+    fn from(value: Decimal) -> Self {
+        Self(value)
+    }
+}
+
+impl From<AgeDto> for Decimal {
+    fn from(value: AgeDto) -> Self {
+        value.0
+    }
+}
+
+impl Display for AgeDto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0.to_string())
+    }
+}
@@ -0,0 +1,26 @@
+use strum_macros::Display;
+
+/// Age range reached by a lifter during the year of a meet
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum BirthYearClassDto {
+    #[strum(to_string = "14-18")]
+    Age14To18,
+
+    #[strum(to_string = "19-23")]
+    Age19To23,
+
+    #[strum(to_string = "24-39")]
+    Age24To39,
+
+    #[strum(to_string = "40-49")]
+    Age40To49,
+
+    #[strum(to_string = "50-59")]
+    Age50To59,
+
+    #[strum(to_string = "60-69")]
+    Age60To69,
+
+    #[strum(to_string = "70-999")]
+    Age70To999,
+}
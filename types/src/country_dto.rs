@@ -1,7 +1,729 @@
-use strum_macros::EnumIter;
+use strum::{EnumProperty, IntoEnumIterator};
+use strum_macros::{Display, EnumIter, EnumProperty, EnumString};
 
-#[derive(Copy, Clone, Debug, Eq, EnumIter, PartialEq)]
+/// Countries used by the opl-data tree, displayed with their opl-data name and stored with
+/// their ISO 3166-1 code. The countries which don't exist anymore use their ISO 3166-3 code,
+/// the nations of the United Kingdom their ISO 3166-2 code, and the territories without a code
+/// a code derived from the one of their country
+#[derive(Copy, Clone, Debug, Display, EnumIter, EnumProperty, EnumString, Eq, Hash, PartialEq)]
 pub enum CountryDto {
-    FRANCE,
-    OTHER,
+    #[strum(serialize = "Abkhazia", props(iso = "GE-AB"))]
+    Abkhazia,
+
+    #[strum(serialize = "Afghanistan", props(iso = "AF"))]
+    Afghanistan,
+
+    #[strum(serialize = "Albania", props(iso = "AL"))]
+    Albania,
+
+    #[strum(serialize = "Algeria", props(iso = "DZ"))]
+    Algeria,
+
+    #[strum(serialize = "American Samoa", props(iso = "AS"))]
+    AmericanSamoa,
+
+    #[strum(serialize = "Andorra", props(iso = "AD"))]
+    Andorra,
+
+    #[strum(serialize = "Angola", props(iso = "AO"))]
+    Angola,
+
+    #[strum(serialize = "Antigua and Barbuda", props(iso = "AG"))]
+    AntiguaAndBarbuda,
+
+    #[strum(serialize = "Argentina", props(iso = "AR"))]
+    Argentina,
+
+    #[strum(serialize = "Armenia", props(iso = "AM"))]
+    Armenia,
+
+    #[strum(serialize = "Aruba", props(iso = "AW"))]
+    Aruba,
+
+    #[strum(serialize = "Australia", props(iso = "AU"))]
+    Australia,
+
+    #[strum(serialize = "Austria", props(iso = "AT"))]
+    Austria,
+
+    #[strum(serialize = "Azerbaijan", props(iso = "AZ"))]
+    Azerbaijan,
+
+    #[strum(serialize = "Bahamas", props(iso = "BS"))]
+    Bahamas,
+
+    #[strum(serialize = "Bahrain", props(iso = "BH"))]
+    Bahrain,
+
+    #[strum(serialize = "Bangladesh", props(iso = "BD"))]
+    Bangladesh,
+
+    #[strum(serialize = "Barbados", props(iso = "BB"))]
+    Barbados,
+
+    #[strum(serialize = "Belarus", props(iso = "BY"))]
+    Belarus,
+
+    #[strum(serialize = "Belgium", props(iso = "BE"))]
+    Belgium,
+
+    #[strum(serialize = "Belize", props(iso = "BZ"))]
+    Belize,
+
+    #[strum(serialize = "Benin", props(iso = "BJ"))]
+    Benin,
+
+    #[strum(serialize = "Bermuda", props(iso = "BM"))]
+    Bermuda,
+
+    #[strum(serialize = "Bhutan", props(iso = "BT"))]
+    Bhutan,
+
+    #[strum(serialize = "Bolivia", props(iso = "BO"))]
+    Bolivia,
+
+    #[strum(serialize = "Bosnia and Herzegovina", props(iso = "BA"))]
+    BosniaAndHerzegovina,
+
+    #[strum(serialize = "Botswana", props(iso = "BW"))]
+    Botswana,
+
+    #[strum(serialize = "Brazil", props(iso = "BR"))]
+    Brazil,
+
+    #[strum(serialize = "British Virgin Islands", props(iso = "VG"))]
+    BritishVirginIslands,
+
+    #[strum(serialize = "Brunei", props(iso = "BN"))]
+    Brunei,
+
+    #[strum(serialize = "Bulgaria", props(iso = "BG"))]
+    Bulgaria,
+
+    #[strum(serialize = "Burkina Faso", props(iso = "BF"))]
+    BurkinaFaso,
+
+    #[strum(serialize = "Burundi", props(iso = "BI"))]
+    Burundi,
+
+    #[strum(serialize = "Cambodia", props(iso = "KH"))]
+    Cambodia,
+
+    #[strum(serialize = "Cameroon", props(iso = "CM"))]
+    Cameroon,
+
+    #[strum(serialize = "Canada", props(iso = "CA"))]
+    Canada,
+
+    #[strum(serialize = "Cape Verde", props(iso = "CV"))]
+    CapeVerde,
+
+    #[strum(serialize = "Cayman Islands", props(iso = "KY"))]
+    CaymanIslands,
+
+    #[strum(serialize = "Central African Republic", props(iso = "CF"))]
+    CentralAfricanRepublic,
+
+    #[strum(serialize = "Chad", props(iso = "TD"))]
+    Chad,
+
+    #[strum(serialize = "Chile", props(iso = "CL"))]
+    Chile,
+
+    #[strum(serialize = "China", props(iso = "CN"))]
+    China,
+
+    #[strum(serialize = "Colombia", props(iso = "CO"))]
+    Colombia,
+
+    #[strum(serialize = "Comoros", props(iso = "KM"))]
+    Comoros,
+
+    #[strum(serialize = "Congo", props(iso = "CG"))]
+    Congo,
+
+    #[strum(serialize = "Cook Islands", props(iso = "CK"))]
+    CookIslands,
+
+    #[strum(serialize = "Costa Rica", props(iso = "CR"))]
+    CostaRica,
+
+    #[strum(serialize = "Croatia", props(iso = "HR"))]
+    Croatia,
+
+    #[strum(serialize = "Cuba", props(iso = "CU"))]
+    Cuba,
+
+    #[strum(serialize = "Curaçao", props(iso = "CW"))]
+    Curacao,
+
+    #[strum(serialize = "Cyprus", props(iso = "CY"))]
+    Cyprus,
+
+    #[strum(serialize = "Czechia", props(iso = "CZ"))]
+    Czechia,
+
+    #[strum(serialize = "Czechoslovakia", props(iso = "CSHH"))]
+    Czechoslovakia,
+
+    #[strum(serialize = "Denmark", props(iso = "DK"))]
+    Denmark,
+
+    #[strum(serialize = "Djibouti", props(iso = "DJ"))]
+    Djibouti,
+
+    #[strum(serialize = "Dominica", props(iso = "DM"))]
+    Dominica,
+
+    #[strum(serialize = "Dominican Republic", props(iso = "DO"))]
+    DominicanRepublic,
+
+    #[strum(serialize = "DR Congo", props(iso = "CD"))]
+    DRCongo,
+
+    #[strum(serialize = "East Germany", props(iso = "DDDE"))]
+    EastGermany,
+
+    #[strum(serialize = "Ecuador", props(iso = "EC"))]
+    Ecuador,
+
+    #[strum(serialize = "Egypt", props(iso = "EG"))]
+    Egypt,
+
+    #[strum(serialize = "El Salvador", props(iso = "SV"))]
+    ElSalvador,
+
+    #[strum(serialize = "England", props(iso = "GB-ENG"))]
+    England,
+
+    #[strum(serialize = "Equatorial Guinea", props(iso = "GQ"))]
+    EquatorialGuinea,
+
+    #[strum(serialize = "Eritrea", props(iso = "ER"))]
+    Eritrea,
+
+    #[strum(serialize = "Estonia", props(iso = "EE"))]
+    Estonia,
+
+    #[strum(serialize = "Eswatini", props(iso = "SZ"))]
+    Eswatini,
+
+    #[strum(serialize = "Ethiopia", props(iso = "ET"))]
+    Ethiopia,
+
+    #[strum(serialize = "Faroe Islands", props(iso = "FO"))]
+    FaroeIslands,
+
+    #[strum(serialize = "Fiji", props(iso = "FJ"))]
+    Fiji,
+
+    #[strum(serialize = "Finland", props(iso = "FI"))]
+    Finland,
+
+    #[strum(serialize = "France", props(iso = "FR"))]
+    France,
+
+    #[strum(serialize = "French Guiana", props(iso = "GF"))]
+    FrenchGuiana,
+
+    #[strum(serialize = "French Polynesia", props(iso = "PF"))]
+    FrenchPolynesia,
+
+    #[strum(serialize = "Gabon", props(iso = "GA"))]
+    Gabon,
+
+    #[strum(serialize = "Gambia", props(iso = "GM"))]
+    Gambia,
+
+    #[strum(serialize = "Georgia", props(iso = "GE"))]
+    Georgia,
+
+    #[strum(serialize = "Germany", props(iso = "DE"))]
+    Germany,
+
+    #[strum(serialize = "Ghana", props(iso = "GH"))]
+    Ghana,
+
+    #[strum(serialize = "Gibraltar", props(iso = "GI"))]
+    Gibraltar,
+
+    #[strum(serialize = "Greece", props(iso = "GR"))]
+    Greece,
+
+    #[strum(serialize = "Greenland", props(iso = "GL"))]
+    Greenland,
+
+    #[strum(serialize = "Grenada", props(iso = "GD"))]
+    Grenada,
+
+    #[strum(serialize = "Guadeloupe", props(iso = "GP"))]
+    Guadeloupe,
+
+    #[strum(serialize = "Guam", props(iso = "GU"))]
+    Guam,
+
+    #[strum(serialize = "Guatemala", props(iso = "GT"))]
+    Guatemala,
+
+    #[strum(serialize = "Guernsey", props(iso = "GG"))]
+    Guernsey,
+
+    #[strum(serialize = "Guinea", props(iso = "GN"))]
+    Guinea,
+
+    #[strum(serialize = "Guinea-Bissau", props(iso = "GW"))]
+    GuineaBissau,
+
+    #[strum(serialize = "Guyana", props(iso = "GY"))]
+    Guyana,
+
+    #[strum(serialize = "Haiti", props(iso = "HT"))]
+    Haiti,
+
+    #[strum(serialize = "Honduras", props(iso = "HN"))]
+    Honduras,
+
+    #[strum(serialize = "Hong Kong", props(iso = "HK"))]
+    HongKong,
+
+    #[strum(serialize = "Hungary", props(iso = "HU"))]
+    Hungary,
+
+    #[strum(serialize = "Iceland", props(iso = "IS"))]
+    Iceland,
+
+    #[strum(serialize = "India", props(iso = "IN"))]
+    India,
+
+    #[strum(serialize = "Indonesia", props(iso = "ID"))]
+    Indonesia,
+
+    #[strum(serialize = "Iran", props(iso = "IR"))]
+    Iran,
+
+    #[strum(serialize = "Iraq", props(iso = "IQ"))]
+    Iraq,
+
+    #[strum(serialize = "Ireland", props(iso = "IE"))]
+    Ireland,
+
+    #[strum(serialize = "Isle of Man", props(iso = "IM"))]
+    IsleOfMan,
+
+    #[strum(serialize = "Israel", props(iso = "IL"))]
+    Israel,
+
+    #[strum(serialize = "Italy", props(iso = "IT"))]
+    Italy,
+
+    #[strum(serialize = "Ivory Coast", props(iso = "CI"))]
+    IvoryCoast,
+
+    #[strum(serialize = "Jamaica", props(iso = "JM"))]
+    Jamaica,
+
+    #[strum(serialize = "Japan", props(iso = "JP"))]
+    Japan,
+
+    #[strum(serialize = "Jersey", props(iso = "JE"))]
+    Jersey,
+
+    #[strum(serialize = "Jordan", props(iso = "JO"))]
+    Jordan,
+
+    #[strum(serialize = "Kazakhstan", props(iso = "KZ"))]
+    Kazakhstan,
+
+    #[strum(serialize = "Kenya", props(iso = "KE"))]
+    Kenya,
+
+    #[strum(serialize = "Kiribati", props(iso = "KI"))]
+    Kiribati,
+
+    #[strum(serialize = "Kosovo", props(iso = "XK"))]
+    Kosovo,
+
+    #[strum(serialize = "Kuwait", props(iso = "KW"))]
+    Kuwait,
+
+    #[strum(serialize = "Kyrgyzstan", props(iso = "KG"))]
+    Kyrgyzstan,
+
+    #[strum(serialize = "Laos", props(iso = "LA"))]
+    Laos,
+
+    #[strum(serialize = "Latvia", props(iso = "LV"))]
+    Latvia,
+
+    #[strum(serialize = "Lebanon", props(iso = "LB"))]
+    Lebanon,
+
+    #[strum(serialize = "Lesotho", props(iso = "LS"))]
+    Lesotho,
+
+    #[strum(serialize = "Liberia", props(iso = "LR"))]
+    Liberia,
+
+    #[strum(serialize = "Libya", props(iso = "LY"))]
+    Libya,
+
+    #[strum(serialize = "Liechtenstein", props(iso = "LI"))]
+    Liechtenstein,
+
+    #[strum(serialize = "Lithuania", props(iso = "LT"))]
+    Lithuania,
+
+    #[strum(serialize = "Luxembourg", props(iso = "LU"))]
+    Luxembourg,
+
+    #[strum(serialize = "Macau", props(iso = "MO"))]
+    Macau,
+
+    #[strum(serialize = "Madagascar", props(iso = "MG"))]
+    Madagascar,
+
+    #[strum(serialize = "Malawi", props(iso = "MW"))]
+    Malawi,
+
+    #[strum(serialize = "Malaysia", props(iso = "MY"))]
+    Malaysia,
+
+    #[strum(serialize = "Maldives", props(iso = "MV"))]
+    Maldives,
+
+    #[strum(serialize = "Mali", props(iso = "ML"))]
+    Mali,
+
+    #[strum(serialize = "Malta", props(iso = "MT"))]
+    Malta,
+
+    #[strum(serialize = "Marshall Islands", props(iso = "MH"))]
+    MarshallIslands,
+
+    #[strum(serialize = "Martinique", props(iso = "MQ"))]
+    Martinique,
+
+    #[strum(serialize = "Mauritania", props(iso = "MR"))]
+    Mauritania,
+
+    #[strum(serialize = "Mauritius", props(iso = "MU"))]
+    Mauritius,
+
+    #[strum(serialize = "Mexico", props(iso = "MX"))]
+    Mexico,
+
+    #[strum(serialize = "Micronesia", props(iso = "FM"))]
+    Micronesia,
+
+    #[strum(serialize = "Moldova", props(iso = "MD"))]
+    Moldova,
+
+    #[strum(serialize = "Monaco", props(iso = "MC"))]
+    Monaco,
+
+    #[strum(serialize = "Mongolia", props(iso = "MN"))]
+    Mongolia,
+
+    #[strum(serialize = "Montenegro", props(iso = "ME"))]
+    Montenegro,
+
+    #[strum(serialize = "Morocco", props(iso = "MA"))]
+    Morocco,
+
+    #[strum(serialize = "Mozambique", props(iso = "MZ"))]
+    Mozambique,
+
+    #[strum(serialize = "Myanmar", props(iso = "MM"))]
+    Myanmar,
+
+    #[strum(serialize = "Namibia", props(iso = "NA"))]
+    Namibia,
+
+    #[strum(serialize = "Nauru", props(iso = "NR"))]
+    Nauru,
+
+    #[strum(serialize = "Nepal", props(iso = "NP"))]
+    Nepal,
+
+    #[strum(serialize = "Netherlands", props(iso = "NL"))]
+    Netherlands,
+
+    #[strum(serialize = "Netherlands Antilles", props(iso = "ANHH"))]
+    NetherlandsAntilles,
+
+    #[strum(serialize = "New Caledonia", props(iso = "NC"))]
+    NewCaledonia,
+
+    #[strum(serialize = "New Zealand", props(iso = "NZ"))]
+    NewZealand,
+
+    #[strum(serialize = "N.Ireland", props(iso = "GB-NIR"))]
+    NorthernIreland,
+
+    #[strum(serialize = "Nicaragua", props(iso = "NI"))]
+    Nicaragua,
+
+    #[strum(serialize = "Niger", props(iso = "NE"))]
+    Niger,
+
+    #[strum(serialize = "Nigeria", props(iso = "NG"))]
+    Nigeria,
+
+    #[strum(serialize = "Niue", props(iso = "NU"))]
+    Niue,
+
+    #[strum(serialize = "North Korea", props(iso = "KP"))]
+    NorthKorea,
+
+    #[strum(serialize = "North Macedonia", props(iso = "MK"))]
+    NorthMacedonia,
+
+    #[strum(serialize = "Norway", props(iso = "NO"))]
+    Norway,
+
+    #[strum(serialize = "Oman", props(iso = "OM"))]
+    Oman,
+
+    #[strum(serialize = "Pakistan", props(iso = "PK"))]
+    Pakistan,
+
+    #[strum(serialize = "Palau", props(iso = "PW"))]
+    Palau,
+
+    #[strum(serialize = "Palestine", props(iso = "PS"))]
+    Palestine,
+
+    #[strum(serialize = "Panama", props(iso = "PA"))]
+    Panama,
+
+    #[strum(serialize = "Papua New Guinea", props(iso = "PG"))]
+    PapuaNewGuinea,
+
+    #[strum(serialize = "Paraguay", props(iso = "PY"))]
+    Paraguay,
+
+    #[strum(serialize = "Peru", props(iso = "PE"))]
+    Peru,
+
+    #[strum(serialize = "Philippines", props(iso = "PH"))]
+    Philippines,
+
+    #[strum(serialize = "Poland", props(iso = "PL"))]
+    Poland,
+
+    #[strum(serialize = "Portugal", props(iso = "PT"))]
+    Portugal,
+
+    #[strum(serialize = "Puerto Rico", props(iso = "PR"))]
+    PuertoRico,
+
+    #[strum(serialize = "Qatar", props(iso = "QA"))]
+    Qatar,
+
+    #[strum(serialize = "Réunion", props(iso = "RE"))]
+    Reunion,
+
+    #[strum(serialize = "Romania", props(iso = "RO"))]
+    Romania,
+
+    #[strum(serialize = "Russia", props(iso = "RU"))]
+    Russia,
+
+    #[strum(serialize = "Rwanda", props(iso = "RW"))]
+    Rwanda,
+
+    #[strum(serialize = "Saint Kitts and Nevis", props(iso = "KN"))]
+    SaintKittsAndNevis,
+
+    #[strum(serialize = "Saint Lucia", props(iso = "LC"))]
+    SaintLucia,
+
+    #[strum(serialize = "Saint Vincent and the Grenadines", props(iso = "VC"))]
+    SaintVincentAndTheGrenadines,
+
+    #[strum(serialize = "Samoa", props(iso = "WS"))]
+    Samoa,
+
+    #[strum(serialize = "San Marino", props(iso = "SM"))]
+    SanMarino,
+
+    #[strum(serialize = "Sao Tome and Principe", props(iso = "ST"))]
+    SaoTomeAndPrincipe,
+
+    #[strum(serialize = "Saudi Arabia", props(iso = "SA"))]
+    SaudiArabia,
+
+    #[strum(serialize = "Scotland", props(iso = "GB-SCT"))]
+    Scotland,
+
+    #[strum(serialize = "Senegal", props(iso = "SN"))]
+    Senegal,
+
+    #[strum(serialize = "Serbia", props(iso = "RS"))]
+    Serbia,
+
+    #[strum(serialize = "Serbia and Montenegro", props(iso = "CSXX"))]
+    SerbiaAndMontenegro,
+
+    #[strum(serialize = "Seychelles", props(iso = "SC"))]
+    Seychelles,
+
+    #[strum(serialize = "Sierra Leone", props(iso = "SL"))]
+    SierraLeone,
+
+    #[strum(serialize = "Singapore", props(iso = "SG"))]
+    Singapore,
+
+    #[strum(serialize = "Slovakia", props(iso = "SK"))]
+    Slovakia,
+
+    #[strum(serialize = "Slovenia", props(iso = "SI"))]
+    Slovenia,
+
+    #[strum(serialize = "Solomon Islands", props(iso = "SB"))]
+    SolomonIslands,
+
+    #[strum(serialize = "Somalia", props(iso = "SO"))]
+    Somalia,
+
+    #[strum(serialize = "South Africa", props(iso = "ZA"))]
+    SouthAfrica,
+
+    #[strum(serialize = "South Korea", props(iso = "KR"))]
+    SouthKorea,
+
+    #[strum(serialize = "South Sudan", props(iso = "SS"))]
+    SouthSudan,
+
+    #[strum(serialize = "Spain", props(iso = "ES"))]
+    Spain,
+
+    #[strum(serialize = "Sri Lanka", props(iso = "LK"))]
+    SriLanka,
+
+    #[strum(serialize = "Sudan", props(iso = "SD"))]
+    Sudan,
+
+    #[strum(serialize = "Suriname", props(iso = "SR"))]
+    Suriname,
+
+    #[strum(serialize = "Sweden", props(iso = "SE"))]
+    Sweden,
+
+    #[strum(serialize = "Switzerland", props(iso = "CH"))]
+    Switzerland,
+
+    #[strum(serialize = "Syria", props(iso = "SY"))]
+    Syria,
+
+    #[strum(serialize = "Tahiti", props(iso = "PF-TA"))]
+    Tahiti,
+
+    #[strum(serialize = "Taiwan", props(iso = "TW"))]
+    Taiwan,
+
+    #[strum(serialize = "Tajikistan", props(iso = "TJ"))]
+    Tajikistan,
+
+    #[strum(serialize = "Tanzania", props(iso = "TZ"))]
+    Tanzania,
+
+    #[strum(serialize = "Thailand", props(iso = "TH"))]
+    Thailand,
+
+    #[strum(serialize = "Timor-Leste", props(iso = "TL"))]
+    TimorLeste,
+
+    #[strum(serialize = "Togo", props(iso = "TG"))]
+    Togo,
+
+    #[strum(serialize = "Tonga", props(iso = "TO"))]
+    Tonga,
+
+    #[strum(serialize = "Trinidad and Tobago", props(iso = "TT"))]
+    TrinidadAndTobago,
+
+    #[strum(serialize = "Tunisia", props(iso = "TN"))]
+    Tunisia,
+
+    #[strum(serialize = "Turkey", props(iso = "TR"))]
+    Turkey,
+
+    #[strum(serialize = "Turkmenistan", props(iso = "TM"))]
+    Turkmenistan,
+
+    #[strum(serialize = "Turks and Caicos", props(iso = "TC"))]
+    TurksAndCaicos,
+
+    #[strum(serialize = "Tuvalu", props(iso = "TV"))]
+    Tuvalu,
+
+    #[strum(serialize = "UAE", props(iso = "AE"))]
+    UAE,
+
+    #[strum(serialize = "Uganda", props(iso = "UG"))]
+    Uganda,
+
+    #[strum(serialize = "UK", props(iso = "GB"))]
+    UK,
+
+    #[strum(serialize = "Ukraine", props(iso = "UA"))]
+    Ukraine,
+
+    #[strum(serialize = "Uruguay", props(iso = "UY"))]
+    Uruguay,
+
+    #[strum(serialize = "USA", props(iso = "US"))]
+    USA,
+
+    #[strum(serialize = "USSR", props(iso = "SUHH"))]
+    USSR,
+
+    #[strum(serialize = "US Virgin Islands", props(iso = "VI"))]
+    USVirginIslands,
+
+    #[strum(serialize = "Uzbekistan", props(iso = "UZ"))]
+    Uzbekistan,
+
+    #[strum(serialize = "Vanuatu", props(iso = "VU"))]
+    Vanuatu,
+
+    #[strum(serialize = "Vatican City", props(iso = "VA"))]
+    VaticanCity,
+
+    #[strum(serialize = "Venezuela", props(iso = "VE"))]
+    Venezuela,
+
+    #[strum(serialize = "Vietnam", props(iso = "VN"))]
+    Vietnam,
+
+    #[strum(serialize = "Wales", props(iso = "GB-WLS"))]
+    Wales,
+
+    #[strum(serialize = "West Germany", props(iso = "DE-W"))]
+    WestGermany,
+
+    #[strum(serialize = "Yemen", props(iso = "YE"))]
+    Yemen,
+
+    #[strum(serialize = "Yugoslavia", props(iso = "YUCS"))]
+    Yugoslavia,
+
+    #[strum(serialize = "Zambia", props(iso = "ZM"))]
+    Zambia,
+
+    #[strum(serialize = "Zimbabwe", props(iso = "ZW"))]
+    Zimbabwe,
+}
+
+impl CountryDto {
+    #[must_use]
+    pub fn iso(self) -> &'static str {
+        self.get_str("iso").expect("every country should have an iso code")
+    }
+
+    #[must_use]
+    pub fn from_iso(iso: &str) -> Option<Self> {
+        Self::iter().find(|country| country.iso() == iso)
+    }
 }
@@ -0,0 +1,35 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct DateDto(pub NaiveDate);
+
+impl From<NaiveDate> for DateDto {
+    fn from(value: NaiveDate) -> Self {
+        Self(value)
+    }
+}
+
+impl From<DateDto> for NaiveDate {
+    fn from(value: DateDto) -> Self {
+        value.0
+    }
+}
+
+impl FromStr for DateDto {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NaiveDate::parse_from_str(s, DATE_FORMAT).map(Self)
+    }
+}
+
+impl Display for DateDto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.format(DATE_FORMAT))
+    }
+}
@@ -14,4 +14,5 @@ pub enum DivisionDto {
     Masters2,
     Masters3,
     Masters4,
+    Unknown,
 }
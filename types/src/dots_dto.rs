@@ -55,16 +55,59 @@ impl DotsDto {
 
     #[must_use]
     pub fn new(sex: SexDto, bodyweight: WeightDto, total: WeightDto) -> Self {
-        todo!()
-        // if bodyweight.is_zero() || total.is_zero() {
-        //     return Self(0);
-        // }
+        if bodyweight.is_zero() || total.is_zero() {
+            return Self(0);
+        }
+
+        let coefficient: f64 = match sex {
+            SexDto::M => Self::dots_coefficient_men(f64::from(bodyweight)),
+            SexDto::F => Self::dots_coefficient_women(f64::from(bodyweight)),
+        };
+
+        Self::from(coefficient * f64::from(total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::prelude::{SexDto, WeightDto};
+
+    use super::DotsDto;
+
+    #[rstest]
+    #[case(SexDto::M, 100., 700., 431)]
+    #[case(SexDto::F, 60., 400., 443)]
+    #[case(SexDto::M, 0., 700., 0)]
+    #[case(SexDto::F, 60., 0., 0)]
+    fn test_new(
+        #[case] sex: SexDto,
+        #[case] bodyweight: f32,
+        #[case] total: f32,
+        #[case] expected: i32,
+    ) {
+        let result: DotsDto = DotsDto::new(sex, WeightDto::from(bodyweight), WeightDto::from(total));
+
+        assert_eq!(DotsDto(expected), result);
+    }
 
-        // let coefficient: f64 = match sex {
-        //     SexDto::M => Self::dots_coefficient_men(f64::from(bodyweight)),
-        //     SexDto::F => Self::dots_coefficient_women(f64::from(bodyweight)),
-        // };
+    #[rstest]
+    #[case(SexDto::M, 30., 40.)]
+    #[case(SexDto::M, 250., 210.)]
+    #[case(SexDto::F, 30., 40.)]
+    #[case(SexDto::F, 200., 150.)]
+    fn test_new_clamps_bodyweight(
+        #[case] sex: SexDto,
+        #[case] bodyweight: f32,
+        #[case] bound: f32,
+    ) {
+        let total: WeightDto = WeightDto::from(500.);
 
-        // Self::from(coefficient * f64::from(total))
+        assert_eq!(
+            DotsDto::new(sex, WeightDto::from(bound), total),
+            DotsDto::new(sex, WeightDto::from(bodyweight), total),
+        );
     }
 }
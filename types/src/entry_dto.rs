@@ -3,10 +3,24 @@ use crate::prelude::*;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EntryDto {
     pub rank: Option<i64>,
+    pub lifter_id: Option<i32>,
+    /// Similarity in percent between the requested name and the name of the lifter
+    pub match_score: Option<u8>,
+    pub place: Option<PlaceDto>,
+    /// Federation of the meet of the entry
+    pub federation: Option<FederationDto>,
     pub name: UsernameDto,
+    pub cyrillic_name: Option<String>,
+    pub japanese_name: Option<String>,
     pub division: DivisionDto,
+    pub division_raw: String,
     pub equipment: EquipmentDto,
+    pub event: EventDto,
     pub sex: SexDto,
+    pub country: Option<CountryDto>,
+    pub age: Option<AgeDto>,
+    pub age_class: Option<AgeClassDto>,
+    pub birth_year_class: Option<BirthYearClassDto>,
     pub bodyweight: WeightDto,
     pub weight_class: Option<WeightClassDto>,
     pub squat1: Option<WeightDto>,
@@ -25,16 +39,46 @@ pub struct EntryDto {
     pub best_bench: Option<WeightDto>,
     pub best_deadlift: Option<WeightDto>,
     pub total: Option<WeightDto>,
+    pub tested: bool,
+}
+
+/// Below this score a match should be confirmed by a human
+const LOW_CONFIDENCE_MATCH_SCORE: u8 = 85;
+
+impl EntryDto {
+    /// Normalized words of the name and of its cyrillic and japanese spellings, the name is
+    /// searched in them
+    #[must_use]
+    pub fn name_normalized(&self) -> String {
+        let mut words: Vec<String> = self.name.parts.clone();
+
+        for name in [&self.cyrillic_name, &self.japanese_name].into_iter().flatten() {
+            words.push(UsernameDto::normalize(name));
+        }
+
+        words.retain(|word| !word.is_empty());
+        words.join(" ")
+    }
 }
 
 impl From<EntryDto> for ExportRow {
     fn from(value: EntryDto) -> Self {
         Self {
             rank: value.rank.map_or_else(|| "None".to_string(), |v| v.to_string()),
+            place: value.place.map_or_else(|| "None".to_string(), |v| v.to_string()),
             name: value.name.name.clone(),
+            match_score: value.match_score.map_or_else(|| "None".to_string(), |v| format!("{v}%")),
+            low_confidence: value.match_score.is_some_and(|v| v < LOW_CONFIDENCE_MATCH_SCORE),
+            country: value.country.map_or_else(|| "None".to_string(), |v| v.to_string()),
+            federation: value.federation.map_or_else(|| "None".to_string(), |v| v.to_string()),
             equipment: value.equipment.to_string(),
+            event: value.event.to_string(),
             sex: value.sex.to_string(),
-            division: value.division.to_string(),
+            age: value.age.map_or_else(|| "None".to_string(), |v| v.to_string()),
+            division: match value.division {
+                DivisionDto::Unknown => value.division_raw.clone(),
+                division => division.to_string(),
+            },
             bodyweight: value.bodyweight.0.to_string(),
             weight_class: value.weight_class.map_or_else(|| "None".to_string(), |v| v.to_string()),
             best_squat: value.best_squat.map_or_else(|| "None".to_string(), |v| v.to_string()),
@@ -0,0 +1,12 @@
+use strum_macros::Display;
+
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum EventDto {
+    SBD,
+    BD,
+    SD,
+    SB,
+    S,
+    B,
+    D,
+}
@@ -1,8 +1,15 @@
 pub struct ExportRow {
     pub rank: String,
+    pub place: String,
     pub name: String,
+    pub match_score: String,
+    pub low_confidence: bool,
+    pub country: String,
+    pub federation: String,
     pub equipment: String,
+    pub event: String,
     pub sex: String,
+    pub age: String,
     pub division: String,
     pub bodyweight: String,
     pub weight_class: String,
@@ -16,9 +23,16 @@ impl Default for ExportRow {
     fn default() -> Self {
         Self {
             rank: String::from("?"),
+            place: String::from("?"),
             name: String::from("?"),
+            match_score: String::from("?"),
+            low_confidence: false,
+            country: String::from("?"),
+            federation: String::from("?"),
             equipment: String::from("?"),
+            event: String::from("?"),
             sex: String::from("?"),
+            age: String::from("?"),
             division: String::from("?"),
             bodyweight: String::from("?"),
             weight_class: String::from("?"),
@@ -1,8 +1,59 @@
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum FederationDto {
-    FFForce,
-    EPF,
-    IPF,
-    FFHMFAC,
-    OTHER,
+use std::fmt::Display;
+
+/// Federations affiliated to the IPF, their meets use the classes of the IPF. `FFForce` has no
+/// classes of its own, its classes are the ones of the IPF
+const IPF_AFFILIATED: [&str; 17] = [
+    "IPF", "EPF", "NAPF", "AsianPF", "OceaniaPF", "AfricanPF", "FESUPO",
+    "FFForce", "BP", "CPU", "PA", "NZPF", "JPA", "SSF", "NSF", "FPR", "BVDK",
+];
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FederationDto(pub String);
+
+impl FederationDto {
+    /// Whether the federation is affiliated to the IPF
+    #[must_use]
+    pub fn is_ipf_affiliated(&self) -> bool {
+        IPF_AFFILIATED.contains(&self.0.as_str())
+    }
+}
+
+impl From<String> for FederationDto {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<FederationDto> for String {
+    fn from(value: FederationDto) -> Self {
+        value.0
+    }
+}
+
+impl Display for FederationDto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::FederationDto;
+
+    #[rstest]
+    #[case("IPF", true)]
+    #[case("FFForce", true)]
+    #[case("EPF", true)]
+    #[case("USAPL", false)]
+    #[case("WRPF", false)]
+    #[case("ipf", false)]
+    fn test_is_ipf_affiliated(
+        #[case] code: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(expected, FederationDto::from(code.to_string()).is_ipf_affiliated());
+    }
 }
@@ -0,0 +1,103 @@
+use serde::Deserialize;
+use strum_macros::{Display, EnumIter};
+
+use crate::prelude::AgeClassDto;
+
+/// Age range of the lifters at the date of the meets
+#[derive(Clone, Copy, Debug, Default, Display, Deserialize, Eq, EnumIter, PartialEq)]
+pub enum AgeClassFilterDto {
+    #[default]
+    #[strum(to_string = "Any")]
+    #[serde(rename(deserialize = "Any"))]
+    Any,
+
+    #[strum(to_string = "5-12")]
+    #[serde(rename(deserialize = "5-12"))]
+    Age5To12,
+
+    #[strum(to_string = "13-15")]
+    #[serde(rename(deserialize = "13-15"))]
+    Age13To15,
+
+    #[strum(to_string = "16-17")]
+    #[serde(rename(deserialize = "16-17"))]
+    Age16To17,
+
+    #[strum(to_string = "18-19")]
+    #[serde(rename(deserialize = "18-19"))]
+    Age18To19,
+
+    #[strum(to_string = "20-23")]
+    #[serde(rename(deserialize = "20-23"))]
+    Age20To23,
+
+    #[strum(to_string = "24-34")]
+    #[serde(rename(deserialize = "24-34"))]
+    Age24To34,
+
+    #[strum(to_string = "35-39")]
+    #[serde(rename(deserialize = "35-39"))]
+    Age35To39,
+
+    #[strum(to_string = "40-44")]
+    #[serde(rename(deserialize = "40-44"))]
+    Age40To44,
+
+    #[strum(to_string = "45-49")]
+    #[serde(rename(deserialize = "45-49"))]
+    Age45To49,
+
+    #[strum(to_string = "50-54")]
+    #[serde(rename(deserialize = "50-54"))]
+    Age50To54,
+
+    #[strum(to_string = "55-59")]
+    #[serde(rename(deserialize = "55-59"))]
+    Age55To59,
+
+    #[strum(to_string = "60-64")]
+    #[serde(rename(deserialize = "60-64"))]
+    Age60To64,
+
+    #[strum(to_string = "65-69")]
+    #[serde(rename(deserialize = "65-69"))]
+    Age65To69,
+
+    #[strum(to_string = "70-74")]
+    #[serde(rename(deserialize = "70-74"))]
+    Age70To74,
+
+    #[strum(to_string = "75-79")]
+    #[serde(rename(deserialize = "75-79"))]
+    Age75To79,
+
+    #[strum(to_string = "80-999")]
+    #[serde(rename(deserialize = "80-999"))]
+    Age80To999,
+}
+
+impl AgeClassFilterDto {
+    /// Age class of the entries, `None` for any
+    #[must_use]
+    pub const fn age_class(self) -> Option<AgeClassDto> {
+        match self {
+            Self::Any => None,
+            Self::Age5To12 => Some(AgeClassDto::Age5To12),
+            Self::Age13To15 => Some(AgeClassDto::Age13To15),
+            Self::Age16To17 => Some(AgeClassDto::Age16To17),
+            Self::Age18To19 => Some(AgeClassDto::Age18To19),
+            Self::Age20To23 => Some(AgeClassDto::Age20To23),
+            Self::Age24To34 => Some(AgeClassDto::Age24To34),
+            Self::Age35To39 => Some(AgeClassDto::Age35To39),
+            Self::Age40To44 => Some(AgeClassDto::Age40To44),
+            Self::Age45To49 => Some(AgeClassDto::Age45To49),
+            Self::Age50To54 => Some(AgeClassDto::Age50To54),
+            Self::Age55To59 => Some(AgeClassDto::Age55To59),
+            Self::Age60To64 => Some(AgeClassDto::Age60To64),
+            Self::Age65To69 => Some(AgeClassDto::Age65To69),
+            Self::Age70To74 => Some(AgeClassDto::Age70To74),
+            Self::Age75To79 => Some(AgeClassDto::Age75To79),
+            Self::Age80To999 => Some(AgeClassDto::Age80To999),
+        }
+    }
+}
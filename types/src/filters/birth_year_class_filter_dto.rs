@@ -0,0 +1,58 @@
+use serde::Deserialize;
+use strum_macros::{Display, EnumIter};
+
+use crate::prelude::BirthYearClassDto;
+
+/// Age range reached by the lifters during the year of the meets
+#[derive(Clone, Copy, Debug, Default, Display, Deserialize, Eq, EnumIter, PartialEq)]
+pub enum BirthYearClassFilterDto {
+    #[default]
+    #[strum(to_string = "Any")]
+    #[serde(rename(deserialize = "Any"))]
+    Any,
+
+    #[strum(to_string = "14-18")]
+    #[serde(rename(deserialize = "14-18"))]
+    Age14To18,
+
+    #[strum(to_string = "19-23")]
+    #[serde(rename(deserialize = "19-23"))]
+    Age19To23,
+
+    #[strum(to_string = "24-39")]
+    #[serde(rename(deserialize = "24-39"))]
+    Age24To39,
+
+    #[strum(to_string = "40-49")]
+    #[serde(rename(deserialize = "40-49"))]
+    Age40To49,
+
+    #[strum(to_string = "50-59")]
+    #[serde(rename(deserialize = "50-59"))]
+    Age50To59,
+
+    #[strum(to_string = "60-69")]
+    #[serde(rename(deserialize = "60-69"))]
+    Age60To69,
+
+    #[strum(to_string = "70-999")]
+    #[serde(rename(deserialize = "70-999"))]
+    Age70To999,
+}
+
+impl BirthYearClassFilterDto {
+    /// Birth year class of the entries, `None` for any
+    #[must_use]
+    pub const fn birth_year_class(self) -> Option<BirthYearClassDto> {
+        match self {
+            Self::Any => None,
+            Self::Age14To18 => Some(BirthYearClassDto::Age14To18),
+            Self::Age19To23 => Some(BirthYearClassDto::Age19To23),
+            Self::Age24To39 => Some(BirthYearClassDto::Age24To39),
+            Self::Age40To49 => Some(BirthYearClassDto::Age40To49),
+            Self::Age50To59 => Some(BirthYearClassDto::Age50To59),
+            Self::Age60To69 => Some(BirthYearClassDto::Age60To69),
+            Self::Age70To999 => Some(BirthYearClassDto::Age70To999),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::prelude::CountryDto;
+
+const ANY: &str = "Any";
+
+/// Countries are sent by their ISO code
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CountryFilterDto {
+    #[default]
+    Any,
+
+    Country(CountryDto),
+}
+
+impl CountryFilterDto {
+    /// Country of the filter, `None` for any
+    #[must_use]
+    pub const fn country(self) -> Option<CountryDto> {
+        match self {
+            Self::Any => None,
+            Self::Country(country) => Some(country),
+        }
+    }
+
+    /// Value of the filter in the html forms
+    #[must_use]
+    pub fn value(self) -> &'static str {
+        match self {
+            Self::Any => ANY,
+            Self::Country(country) => country.iso(),
+        }
+    }
+}
+
+impl FromStr for CountryFilterDto {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "" | ANY => Ok(Self::Any),
+            iso => CountryDto::from_iso(iso)
+                .map(Self::Country)
+                .ok_or_else(|| format!("unknown country code \"{iso}\"")),
+        }
+    }
+}
+
+impl Display for CountryFilterDto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => f.write_str(ANY),
+            Self::Country(country) => country.fmt(f),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CountryFilterDto {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(CountryFilterVisitor)
+    }
+}
+
+struct CountryFilterVisitor;
+
+impl Visitor<'_> for CountryFilterVisitor {
+    type Value = CountryFilterDto;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("\"Any\" or a country code")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Self::Value::from_str(v).map_err(E::custom)
+    }
+}
@@ -1,6 +1,8 @@
 use serde::Deserialize;
 use strum_macros::{Display, EnumIter};
 
+use crate::prelude::DivisionDto;
+
 #[derive(Clone, Copy, Debug, Display, Deserialize, Eq, EnumIter, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum DivisionFilterDto {
@@ -61,3 +63,25 @@ pub enum DivisionFilterDto {
     #[serde(rename(deserialize = "Masters 4"))]
     Masters4,
 }
+
+impl DivisionFilterDto {
+    /// Division of the entries, `None` for any
+    #[must_use]
+    pub const fn division(self) -> Option<DivisionDto> {
+        match self {
+            Self::Any => None,
+            Self::Open => Some(DivisionDto::Open),
+            Self::G => Some(DivisionDto::G),
+            Self::Cadet => Some(DivisionDto::Cadet),
+            Self::Elite => Some(DivisionDto::Elite),
+            Self::SubJuniors => Some(DivisionDto::SubJuniors),
+            Self::Juniors => Some(DivisionDto::Juniors),
+            Self::Masters => Some(DivisionDto::Masters),
+            Self::Seniors => Some(DivisionDto::Seniors),
+            Self::Masters1 => Some(DivisionDto::Masters1),
+            Self::Masters2 => Some(DivisionDto::Masters2),
+            Self::Masters3 => Some(DivisionDto::Masters3),
+            Self::Masters4 => Some(DivisionDto::Masters4),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use serde::Deserialize;
 use strum_macros::{Display, EnumIter};
 
+use crate::prelude::EquipmentDto;
+
 #[derive(Clone, Copy, Debug, Deserialize, Display, EnumIter, Eq, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum EquipmentFilterDto {
@@ -37,3 +39,20 @@ pub enum EquipmentFilterDto {
     #[strum(to_string = "Unlimited")]
     Unlimited,
 }
+
+impl EquipmentFilterDto {
+    /// Equipment of the entries
+    #[must_use]
+    pub const fn equipment(self) -> EquipmentDto {
+        match self {
+            Self::Raw => EquipmentDto::Raw,
+            Self::Wraps => EquipmentDto::Wraps,
+            Self::Single => EquipmentDto::Single,
+            Self::Multi => EquipmentDto::Multi,
+            Self::Straps => EquipmentDto::Straps,
+            Self::Sleeves => EquipmentDto::Sleeves,
+            Self::Bare => EquipmentDto::Bare,
+            Self::Unlimited => EquipmentDto::Unlimited,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde::Deserialize;
+use strum_macros::{Display, EnumIter};
+
+use crate::prelude::EventDto;
+
+/// Events are never mixed in a ranking, a bench only total can't be compared to a full power one
+#[derive(Clone, Copy, Debug, Default, Display, Deserialize, Eq, EnumIter, PartialEq)]
+pub enum EventFilterDto {
+    #[default]
+    #[strum(to_string = "SBD")]
+    #[serde(rename(deserialize = "SBD"))]
+    SBD,
+
+    #[strum(to_string = "BD")]
+    #[serde(rename(deserialize = "BD"))]
+    BD,
+
+    #[strum(to_string = "SD")]
+    #[serde(rename(deserialize = "SD"))]
+    SD,
+
+    #[strum(to_string = "SB")]
+    #[serde(rename(deserialize = "SB"))]
+    SB,
+
+    #[strum(to_string = "S")]
+    #[serde(rename(deserialize = "S"))]
+    S,
+
+    #[strum(to_string = "B")]
+    #[serde(rename(deserialize = "B"))]
+    B,
+
+    #[strum(to_string = "D")]
+    #[serde(rename(deserialize = "D"))]
+    D,
+}
+
+impl EventFilterDto {
+    /// Event of the entries
+    #[must_use]
+    pub const fn event(self) -> EventDto {
+        match self {
+            Self::SBD => EventDto::SBD,
+            Self::BD => EventDto::BD,
+            Self::SD => EventDto::SD,
+            Self::SB => EventDto::SB,
+            Self::S => EventDto::S,
+            Self::B => EventDto::B,
+            Self::D => EventDto::D,
+        }
+    }
+}
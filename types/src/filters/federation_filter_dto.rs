@@ -1,31 +1,69 @@
-use serde::Deserialize;
-use strum_macros::{Display, EnumIter};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::convert::Infallible;
+use std::fmt::{self, Display};
+use std::str::FromStr;
 
-#[derive(Copy, Clone, Debug, Deserialize, Default, Display, Eq, PartialEq, EnumIter)]
-#[serde(rename_all = "lowercase")]
+use crate::prelude::FederationDto;
+
+const ANY: &str = "Any";
+
+/// The federations are the ones of the imported meets, they are not known at compile time
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub enum FederationFilterDto {
-    #[strum(to_string = "Any")]
-    #[serde(rename(deserialize = "Any"))]
+    #[default]
     Any,
 
-    #[strum(to_string = "FFForce")]
-    #[serde(rename(deserialize = "FFForce"))]
-    FFForce,
+    Federation(FederationDto),
+}
 
-    #[strum(to_string = "EPF")]
-    #[serde(rename(deserialize = "EPF"))]
-    EPF,
+impl FederationFilterDto {
+    /// Federation of the filter, `None` for any
+    #[must_use]
+    pub const fn federation(&self) -> Option<&FederationDto> {
+        match self {
+            Self::Any => None,
+            Self::Federation(federation) => Some(federation),
+        }
+    }
+}
 
-    #[strum(to_string = "IPF")]
-    #[serde(rename(deserialize = "IPF"))]
-    IPF,
+impl FromStr for FederationFilterDto {
+    type Err = Infallible;
 
-    #[strum(to_string = "FFHMFAC")]
-    #[serde(rename(deserialize = "FFHMFAC"))]
-    FFHMFAC,
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "" | ANY => Ok(Self::Any),
+            code => Ok(Self::Federation(FederationDto(code.to_string()))),
+        }
+    }
+}
 
-    #[strum(to_string = "Other")]
-    #[serde(other)]
-    #[default]
-    OTHER,
+impl Display for FederationFilterDto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => f.write_str(ANY),
+            Self::Federation(federation) => federation.fmt(f),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FederationFilterDto {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FederationFilterVisitor)
+    }
+}
+
+struct FederationFilterVisitor;
+
+impl Visitor<'_> for FederationFilterVisitor {
+    type Value = FederationFilterDto;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("\"Any\" or a federation code")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Self::Value::from_str(v).map_err(E::custom)
+    }
 }
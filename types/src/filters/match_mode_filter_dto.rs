@@ -0,0 +1,17 @@
+use serde::Deserialize;
+use strum_macros::{Display, EnumIter};
+
+/// How the requested names are compared to the names of the lifters
+#[derive(Clone, Copy, Debug, Default, Display, Deserialize, Eq, EnumIter, PartialEq)]
+pub enum MatchModeFilterDto {
+    /// Every word of the requested name is a word of the name of the lifter
+    #[default]
+    #[strum(to_string = "Exact")]
+    #[serde(rename(deserialize = "Exact"))]
+    Exact,
+
+    /// The words may contain typos, each result has a score
+    #[strum(to_string = "Fuzzy")]
+    #[serde(rename(deserialize = "Fuzzy"))]
+    Fuzzy,
+}
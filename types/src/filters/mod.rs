@@ -1,11 +1,27 @@
+mod age_class_filter_dto;
+mod birth_year_class_filter_dto;
+mod country_filter_dto;
 mod division_filter_dto;
 mod equipment_filter_dto;
+mod event_filter_dto;
 mod federation_filter_dto;
+mod match_mode_filter_dto;
 mod query_dto;
 mod sex_filter_dto;
+mod tested_filter_dto;
+mod weight_class_filter_dto;
+mod weight_class_options_dto;
 
+pub use age_class_filter_dto::AgeClassFilterDto;
+pub use birth_year_class_filter_dto::BirthYearClassFilterDto;
+pub use country_filter_dto::CountryFilterDto;
 pub use division_filter_dto::DivisionFilterDto;
 pub use equipment_filter_dto::EquipmentFilterDto;
+pub use event_filter_dto::EventFilterDto;
 pub use federation_filter_dto::FederationFilterDto;
+pub use match_mode_filter_dto::MatchModeFilterDto;
 pub use query_dto::QueryDto;
 pub use sex_filter_dto::SexFilterDto;
+pub use tested_filter_dto::TestedFilterDto;
+pub use weight_class_filter_dto::WeightClassFilterDto;
+pub use weight_class_options_dto::WeightClassOptionsDto;
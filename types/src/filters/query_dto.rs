@@ -1,12 +1,136 @@
+use serde::de::{self, Deserializer};
 use serde::Deserialize;
+use std::fmt::Display;
+use std::str::FromStr;
 
-use crate::filters::{DivisionFilterDto, EquipmentFilterDto, FederationFilterDto, SexFilterDto};
+use crate::filters::{AgeClassFilterDto, BirthYearClassFilterDto, CountryFilterDto, DivisionFilterDto, EquipmentFilterDto, EventFilterDto, FederationFilterDto, MatchModeFilterDto, SexFilterDto, TestedFilterDto, WeightClassFilterDto};
+use crate::prelude::{DateDto, DivisionDto, EntryDto, EquipmentDto, FederationDto, MeetDataDto, PlaceDto, SexDto};
 
+/// The federation, equipment, sex and division filters take several values, sent as repeated
+/// keys by the multi-selects. No value or "Any" among them accepts any value
 #[derive(Debug, Deserialize)]
 pub struct QueryDto {
-    pub federation_choice: FederationFilterDto,
-    pub equipment_choice: EquipmentFilterDto,
-    pub sex_choice: SexFilterDto,
-    pub division_choice: DivisionFilterDto,
+    #[serde(default)]
+    pub federation_choice: Vec<FederationFilterDto>,
+
+    #[serde(default)]
+    pub equipment_choice: Vec<EquipmentFilterDto>,
+
+    #[serde(default)]
+    pub sex_choice: Vec<SexFilterDto>,
+
+    #[serde(default)]
+    pub division_choice: Vec<DivisionFilterDto>,
+
+    #[serde(default)]
+    pub event_choice: EventFilterDto,
+
+    #[serde(default)]
+    pub age_class_choice: AgeClassFilterDto,
+
+    #[serde(default)]
+    pub birth_year_class_choice: BirthYearClassFilterDto,
+
+    #[serde(default)]
+    pub tested_choice: TestedFilterDto,
+
+    #[serde(default)]
+    pub weight_class_choice: WeightClassFilterDto,
+
+    /// Ranks the lifters in the class of their bodyweight rather than the class they entered
+    #[serde(default)]
+    pub rank_by_bodyweight: bool,
+
+    #[serde(default)]
+    pub meet_country_choice: CountryFilterDto,
+
+    #[serde(default)]
+    pub lifter_country_choice: CountryFilterDto,
+
+    #[serde(default)]
+    pub exclude_disqualified: bool,
+
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_from: Option<DateDto>,
+
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub date_to: Option<DateDto>,
+
+    #[serde(default)]
+    pub match_mode_choice: MatchModeFilterDto,
+
     pub powerlifters: String,
 }
+
+impl QueryDto {
+    /// Federations of the meets, `None` for any
+    #[must_use]
+    pub fn federations(&self) -> Option<Vec<&FederationDto>> {
+        chosen(&self.federation_choice, FederationFilterDto::federation)
+    }
+
+    /// Equipments of the entries, `None` for any
+    #[must_use]
+    pub fn equipments(&self) -> Option<Vec<EquipmentDto>> {
+        chosen(&self.equipment_choice, |equipment| Some(equipment.equipment()))
+    }
+
+    /// Sexes of the lifters, `None` for any
+    #[must_use]
+    pub fn sexes(&self) -> Option<Vec<SexDto>> {
+        chosen(&self.sex_choice, |sex| sex.sex())
+    }
+
+    /// Divisions of the entries, `None` for any
+    #[must_use]
+    pub fn divisions(&self) -> Option<Vec<DivisionDto>> {
+        chosen(&self.division_choice, |division| division.division())
+    }
+
+    /// Whether an entry of a meet passes the filters, the lifters are matched apart
+    #[must_use]
+    pub fn matches(&self, meet: &MeetDataDto, entry: &EntryDto) -> bool {
+        entry.total.is_some()
+            && accepts(&self.federation_choice, FederationFilterDto::federation, &&meet.federation)
+            && self.meet_country_choice.country().is_none_or(|country| meet.country == Some(country))
+            && self.lifter_country_choice.country().is_none_or(|country| entry.country == Some(country))
+            && accepts(&self.sex_choice, |sex| sex.sex(), &entry.sex)
+            && self.age_class_choice.age_class().is_none_or(|age_class| entry.age_class == Some(age_class))
+            && self.birth_year_class_choice.birth_year_class().is_none_or(|birth_year_class| entry.birth_year_class == Some(birth_year_class))
+            && accepts(&self.division_choice, |division| division.division(), &entry.division)
+            && self.weight_class_choice.matches(entry, self.rank_by_bodyweight)
+            && accepts(&self.equipment_choice, |equipment| Some(equipment.equipment()), &entry.equipment)
+            && entry.event == self.event_choice.event()
+            && self.tested_choice.tested().is_none_or(|tested| entry.tested == tested)
+            && !(self.exclude_disqualified && entry.place.is_some_and(PlaceDto::is_disqualified))
+            && self.date_from.is_none_or(|date_from| meet.date.is_some_and(|date| date >= date_from))
+            && self.date_to.is_none_or(|date_to| meet.date.is_some_and(|date| date <= date_to))
+    }
+}
+
+/// Values of a multi-value filter, `None` when no value or "Any" is chosen
+fn chosen<'a, T, U>(choices: &'a [T], value: impl Fn(&'a T) -> Option<U>) -> Option<Vec<U>> {
+    let values: Option<Vec<U>> = choices.iter().map(value).collect();
+
+    values.filter(|values| !values.is_empty())
+}
+
+/// Whether a multi-value filter accepts a value, without collecting the values of the filter
+fn accepts<'a, T, U: PartialEq>(choices: &'a [T], value: impl Fn(&'a T) -> Option<U>, actual: &U) -> bool {
+    choices.is_empty() || choices.iter().any(|choice| value(choice).is_none_or(|value| value == *actual))
+}
+
+/// Html forms send empty inputs as empty strings, they are treated as a missing value
+fn empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let value: Option<String> = Option::deserialize(deserializer)?;
+
+    match value.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(value) => value.parse().map(Some).map_err(de::Error::custom),
+    }
+}
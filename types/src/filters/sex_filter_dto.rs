@@ -1,6 +1,8 @@
 use serde::Deserialize;
 use strum_macros::{Display, EnumIter};
 
+use crate::prelude::SexDto;
+
 #[derive(Clone, Copy, Debug, Display, Deserialize, Eq, EnumIter, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum SexFilterDto {
@@ -18,3 +20,15 @@ pub enum SexFilterDto {
     #[serde(rename(deserialize = "Women"))]
     F,
 }
+
+impl SexFilterDto {
+    /// Sex of the lifters, `None` for any
+    #[must_use]
+    pub const fn sex(self) -> Option<SexDto> {
+        match self {
+            Self::Any => None,
+            Self::M => Some(SexDto::M),
+            Self::F => Some(SexDto::F),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use serde::Deserialize;
+use strum_macros::{Display, EnumIter};
+
+#[derive(Clone, Copy, Debug, Default, Display, Deserialize, Eq, EnumIter, PartialEq)]
+pub enum TestedFilterDto {
+    #[default]
+    #[strum(to_string = "Any")]
+    #[serde(rename(deserialize = "Any"))]
+    Any,
+
+    #[strum(to_string = "Tested")]
+    #[serde(rename(deserialize = "Tested"))]
+    Tested,
+
+    #[strum(to_string = "Untested")]
+    #[serde(rename(deserialize = "Untested"))]
+    Untested,
+}
+
+impl TestedFilterDto {
+    /// Whether the entries are drug tested, `None` for any
+    #[must_use]
+    pub const fn tested(self) -> Option<bool> {
+        match self {
+            Self::Any => None,
+            Self::Tested => Some(true),
+            Self::Untested => Some(false),
+        }
+    }
+}
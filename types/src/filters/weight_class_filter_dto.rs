@@ -0,0 +1,138 @@
+use rust_decimal::Decimal;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+
+use crate::filters::{FederationFilterDto, SexFilterDto};
+use crate::prelude::{EntryDto, SexDto, WeightClassDto, WeightClassSetDto};
+
+const ANY: &str = "Any";
+const SEPARATOR: char = '/';
+const OVER_SUFFIX: char = '+';
+
+/// A class only makes sense within the classes of its set and sex, they are sent along with
+/// it, like "IPF/M/120+"
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum WeightClassFilterDto {
+    #[default]
+    Any,
+
+    Class {
+        set: WeightClassSetDto,
+        sex: SexDto,
+        class: WeightClassDto,
+    },
+}
+
+impl WeightClassFilterDto {
+    /// Classes offered for the chosen federations and sexes, grouped by set and sex
+    #[must_use]
+    pub fn options(federations: &[FederationFilterDto], sexes: &[SexFilterDto]) -> Vec<(WeightClassSetDto, SexDto, Vec<Self>)> {
+        let sets: Vec<WeightClassSetDto> = WeightClassSetDto::iter()
+            .filter(|set| {
+                federations.is_empty() || federations
+                    .iter()
+                    .any(|federation| WeightClassSetDto::of_federation(federation.federation()).contains(set))
+            })
+            .collect();
+        let sexes: Vec<SexDto> = [SexDto::M, SexDto::F]
+            .into_iter()
+            .filter(|&sex| sexes.is_empty() || sexes.iter().any(|choice| choice.sex().is_none_or(|choice| choice == sex)))
+            .collect();
+
+        sets
+            .into_iter()
+            .flat_map(|set| sexes.iter().map(move |&sex| {
+                (set, sex, set.classes(sex).into_iter().map(|class| Self::Class { set, sex, class }).collect())
+            }))
+            .collect()
+    }
+
+    /// Whether an entry is in the class, the one it entered or the one of its bodyweight
+    #[must_use]
+    pub fn matches(self, entry: &EntryDto, by_bodyweight: bool) -> bool {
+        let Self::Class { set, sex, class } = self else {
+            return true;
+        };
+
+        entry.sex == sex && if by_bodyweight {
+            set.bodyweights(sex, class).is_some_and(|(lower, upper)| {
+                entry.bodyweight > lower && upper.is_none_or(|upper| entry.bodyweight <= upper)
+            })
+        } else {
+            entry.weight_class == Some(class)
+        }
+    }
+
+    /// Value of the filter in the html forms
+    #[must_use]
+    pub fn value(self) -> String {
+        match self {
+            Self::Any => ANY.to_string(),
+            Self::Class { set, sex, class } => format!("{set}{SEPARATOR}{sex}{SEPARATOR}{class}"),
+        }
+    }
+}
+
+impl FromStr for WeightClassFilterDto {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: &str = s.trim();
+        if s.is_empty() || s == ANY {
+            return Ok(Self::Any);
+        }
+
+        let mut parts = s.split(SEPARATOR);
+        let (Some(set), Some(sex), Some(class), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+            return Err(format!("weight class \"{s}\" should be like \"IPF/M/120+\""));
+        };
+
+        let set: WeightClassSetDto = set.parse().map_err(|_| format!("unknown weight class set \"{set}\""))?;
+        let sex: SexDto = match sex {
+            "M" => SexDto::M,
+            "F" => SexDto::F,
+            _ => return Err(format!("unknown sex \"{sex}\"")),
+        };
+        let (limit, over): (&str, bool) = class.strip_suffix(OVER_SUFFIX).map_or((class, false), |limit| (limit, true));
+        let limit: Decimal = limit.parse().map_err(|_| format!("invalid weight class \"{class}\""))?;
+        let class: WeightClassDto = WeightClassDto::new(limit.normalize().into(), over);
+
+        if set.bodyweights(sex, class).is_none() {
+            return Err(format!("weight class \"{class}\" isn't a class of {set}"));
+        }
+
+        Ok(Self::Class { set, sex, class })
+    }
+}
+
+impl Display for WeightClassFilterDto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => f.write_str(ANY),
+            Self::Class { class, .. } => class.fmt(f),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WeightClassFilterDto {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(WeightClassFilterVisitor)
+    }
+}
+
+struct WeightClassFilterVisitor;
+
+impl Visitor<'_> for WeightClassFilterVisitor {
+    type Value = WeightClassFilterDto;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("\"Any\" or a weight class like \"IPF/M/120+\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Self::Value::from_str(v).map_err(E::custom)
+    }
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+use crate::filters::{FederationFilterDto, SexFilterDto};
+
+/// Choices of the search form the weight classes depend on
+#[derive(Debug, Deserialize)]
+pub struct WeightClassOptionsDto {
+    #[serde(default)]
+    pub federation_choice: Vec<FederationFilterDto>,
+
+    #[serde(default)]
+    pub sex_choice: Vec<SexFilterDto>,
+}
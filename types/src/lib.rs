@@ -1,16 +1,23 @@
+mod age_class_dto;
+mod age_dto;
+mod birth_year_class_dto;
 mod country_dto;
+mod date_dto;
 mod division_dto;
 mod dots_dto;
 mod entry_dto;
 mod equipment_dto;
+mod event_dto;
 mod export_row;
 mod federation_dto;
 mod meet_data_dto;
 mod meet_dto;
 mod place_dto;
+mod search_result_dto;
 mod sex_dto;
 mod username_dto;
 mod weight_class_dto;
+mod weight_class_set_dto;
 mod weight_dto;
 
 pub mod filters;
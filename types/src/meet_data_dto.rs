@@ -1,9 +1,11 @@
 use crate::prelude::*;
 
+#[derive(Debug)]
 pub struct MeetDataDto {
     pub name: String,
     pub federation: FederationDto,
-    pub country: CountryDto,
+    pub country: Option<CountryDto>,
     pub state: String,
     pub town: String,
+    pub date: Option<DateDto>,
 }
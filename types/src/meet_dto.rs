@@ -1,6 +1,10 @@
 use crate::prelude::*;
 
 pub struct MeetDto {
+    /// Directory of the meet, relative to the root of the opl-data tree
+    pub path: String,
+    /// Hash of the meet files content
+    pub hash: String,
     pub data: MeetDataDto,
     pub entries: Vec<EntryDto>,
 }
@@ -1,4 +1,9 @@
-#[derive(Debug)]
+use std::fmt::Display;
+use std::str::FromStr;
+
+use anyhow::{bail, Error, Result};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PlaceDto {
     /// The placing assigned to the entry.
     P(u8),
@@ -11,3 +16,81 @@ pub enum PlaceDto {
     /// No-Show.
     NS,
 }
+
+impl PlaceDto {
+    /// Places that do not correspond to a valid result
+    pub const DISQUALIFIED: [Self; 3] = [Self::DQ, Self::DD, Self::NS];
+
+    #[must_use]
+    pub const fn is_disqualified(self) -> bool {
+        matches!(self, Self::DQ | Self::DD | Self::NS)
+    }
+}
+
+impl FromStr for PlaceDto {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "G" => Ok(Self::G),
+            "DQ" => Ok(Self::DQ),
+            "DD" => Ok(Self::DD),
+            "NS" => Ok(Self::NS),
+            _ => match s.parse::<u8>() {
+                Ok(0) => bail!("place \"{s}\" should be greater than 0"),
+                Ok(place) => Ok(Self::P(place)),
+                Err(e) => bail!("place \"{s}\" is invalid: {e}"),
+            },
+        }
+    }
+}
+
+impl Display for PlaceDto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::P(place) => write!(f, "{place}"),
+            Self::G => f.write_str("G"),
+            Self::DQ => f.write_str("DQ"),
+            Self::DD => f.write_str("DD"),
+            Self::NS => f.write_str("NS"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::PlaceDto;
+
+    #[rstest]
+    #[case("1", PlaceDto::P(1))]
+    #[case("255", PlaceDto::P(255))]
+    #[case("G", PlaceDto::G)]
+    #[case("DQ", PlaceDto::DQ)]
+    #[case("DD", PlaceDto::DD)]
+    #[case("NS", PlaceDto::NS)]
+    fn test_from_str(
+        #[case] input: &str,
+        #[case] expected: PlaceDto,
+    ) {
+        let result: Result<PlaceDto> = input.parse::<PlaceDto>();
+
+        assert!(result.is_ok());
+        assert_eq!(expected, result.unwrap());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("0")]
+    #[case("-1")]
+    #[case("256")]
+    #[case("dq")]
+    fn test_from_str_error(#[case] input: &str) {
+        let result: Result<PlaceDto> = input.parse::<PlaceDto>();
+
+        assert!(result.is_err());
+    }
+}
@@ -1,14 +1,21 @@
+pub use crate::age_class_dto::AgeClassDto;
+pub use crate::age_dto::AgeDto;
+pub use crate::birth_year_class_dto::BirthYearClassDto;
 pub use crate::country_dto::CountryDto;
+pub use crate::date_dto::DateDto;
 pub use crate::division_dto::DivisionDto;
 pub use crate::dots_dto::DotsDto;
 pub use crate::entry_dto::EntryDto;
 pub use crate::equipment_dto::EquipmentDto;
+pub use crate::event_dto::EventDto;
 pub use crate::export_row::ExportRow;
 pub use crate::federation_dto::FederationDto;
 pub use crate::meet_data_dto::MeetDataDto;
 pub use crate::meet_dto::MeetDto;
 pub use crate::place_dto::PlaceDto;
+pub use crate::search_result_dto::{AmbiguousNameDto, CandidateDto, AmbiguousNameExport, ExportResult, SearchResultDto};
 pub use crate::sex_dto::SexDto;
 pub use crate::username_dto::UsernameDto;
 pub use crate::weight_class_dto::WeightClassDto;
+pub use crate::weight_class_set_dto::WeightClassSetDto;
 pub use crate::weight_dto::WeightDto;
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use crate::filters::MatchModeFilterDto;
+use crate::prelude::*;
+
+/// Requested name matching several lifters, none of them is ranked until the name is made
+/// more precise
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmbiguousNameDto {
+    pub name: String,
+    pub candidates: Vec<EntryDto>,
+}
+
+/// Outcome of a search for each requested name: the lifter it matches, nothing, or several
+/// lifters
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SearchResultDto {
+    pub matched: Vec<EntryDto>,
+    pub unmatched: Vec<String>,
+    pub ambiguous: Vec<AmbiguousNameDto>,
+}
+
+/// Best entry of a lifter passing the filters of a search, which the requested names are
+/// matched against
+#[derive(Clone, Copy, Debug)]
+pub struct CandidateDto<'a> {
+    pub rank: i64,
+    pub entry: &'a EntryDto,
+    /// Normalized words of the names of the lifter
+    pub name_normalized: &'a str,
+}
+
+impl SearchResultDto {
+    /// Matches each requested name, one per line, against the candidates sorted by rank
+    #[must_use]
+    pub fn from_candidates(powerlifters: &str, mode: MatchModeFilterDto, candidates: &[CandidateDto]) -> Self {
+        let mut output: Self = Self::default();
+        let mut lifter_ids: HashSet<i32> = HashSet::new();
+
+        for powerlifter in powerlifters.lines() {
+            let name: &str = powerlifter.trim();
+            let (parts, disambiguation): (Vec<String>, Option<u16>) = UsernameDto::split(name);
+            if parts.is_empty() {
+                continue;
+            }
+            let username: UsernameDto = UsernameDto::new(name, parts, disambiguation);
+
+            let mut matches: Vec<(u8, &CandidateDto)> = candidates
+                .iter()
+                .filter_map(|candidate| Some((candidate.entry.name.match_score(&username, candidate.name_normalized, mode)?, candidate)))
+                .collect();
+            // Only the best matches are kept, so a close name doesn't make an exact match
+            // ambiguous, the sort is stable and keeps the ranks in order
+            matches.sort_by(|(left, _), (right, _)| right.cmp(left));
+            if let Some(&(best, _)) = matches.first() {
+                matches.retain(|&(match_score, _)| match_score == best);
+            }
+
+            let mut matches: Vec<EntryDto> = matches
+                .into_iter()
+                .map(|(match_score, candidate)| {
+                    let mut entry: EntryDto = candidate.entry.clone();
+                    entry.rank = Some(candidate.rank);
+                    entry.match_score = Some(match_score);
+                    entry
+                })
+                .collect();
+
+            match matches.len() {
+                0 => output.unmatched.push(name.to_owned()),
+                1 => {
+                    let entry: EntryDto = matches.remove(0);
+                    // A lifter requested on several lines is listed once
+                    if entry.lifter_id.is_none_or(|lifter_id| lifter_ids.insert(lifter_id)) {
+                        output.matched.push(entry);
+                    }
+                },
+                _ => output.ambiguous.push(AmbiguousNameDto {
+                    name: name.to_owned(),
+                    candidates: matches,
+                }),
+            }
+        }
+
+        output
+    }
+}
+
+/// Requested name matching several lifters, ready to be displayed
+pub struct AmbiguousNameExport {
+    pub name: String,
+    pub candidates: Vec<ExportRow>,
+}
+
+/// Outcome of a search ready to be displayed
+#[derive(Default)]
+pub struct ExportResult {
+    pub matched: Vec<ExportRow>,
+    pub unmatched: Vec<String>,
+    pub ambiguous: Vec<AmbiguousNameExport>,
+}
+
+impl From<AmbiguousNameDto> for AmbiguousNameExport {
+    fn from(value: AmbiguousNameDto) -> Self {
+        Self {
+            name: value.name,
+            candidates: value.candidates.into_iter().map(ExportRow::from).collect(),
+        }
+    }
+}
+
+impl From<SearchResultDto> for ExportResult {
+    fn from(value: SearchResultDto) -> Self {
+        Self {
+            matched: value.matched.into_iter().map(ExportRow::from).collect(),
+            unmatched: value.unmatched,
+            ambiguous: value.ambiguous.into_iter().map(AmbiguousNameExport::from).collect(),
+        }
+    }
+}
@@ -1,16 +1,120 @@
+use std::ops::RangeInclusive;
+use strsim::normalized_damerau_levenshtein;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::filters::MatchModeFilterDto;
+
+/// Opl-data adds a number to the names of homonyms, like "Thomas Martin #2"
+const DISAMBIGUATION_PREFIX: char = '#';
+
+/// Accents of the latin scripts, the other combining marks like the japanese dakuten change
+/// the letter and are kept
+const DIACRITICS: RangeInclusive<char> = '\u{0300}'..='\u{036F}';
+
+/// Lowest score in percent of a fuzzy match
+const MIN_FUZZY_MATCH_SCORE: u8 = 60;
+
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct UsernameDto {
     pub name: String,
     pub parts: Vec<String>,
+    pub disambiguation: Option<u16>,
 }
 
 impl UsernameDto {
     #[must_use]
-    pub fn new(name: &str, parts: Vec<String>) -> Self {
+    pub fn new(name: &str, parts: Vec<String>, disambiguation: Option<u16>) -> Self {
         Self {
             name: name.to_string(),
             parts,
+            disambiguation,
+        }
+    }
+
+    /// Normalized words of the name, without the disambiguation number which is returned apart
+    #[must_use]
+    pub fn split(name: &str) -> (Vec<String>, Option<u16>) {
+        let mut parts: Vec<&str> = name.split_whitespace().collect();
+
+        let disambiguation: Option<u16> = parts
+            .last()
+            .and_then(|part| part.strip_prefix(DISAMBIGUATION_PREFIX))
+            .and_then(|number| number.parse().ok());
+        if disambiguation.is_some() {
+            parts.pop();
+        }
+
+        let parts: Vec<String> = Self::normalize(&parts.join(" "))
+            .split_whitespace()
+            .map(str::to_owned)
+            .collect();
+
+        (parts, disambiguation)
+    }
+
+    /// Score in percent of the requested name against this name, whose normalized words are
+    /// `name_normalized`, the disambiguation number only has to match when it is requested.
+    /// In the exact mode every requested word is a word of the name, in the fuzzy mode each
+    /// requested word is compared to the closest word of the name
+    #[must_use]
+    pub fn match_score(&self, requested: &Self, name_normalized: &str, mode: MatchModeFilterDto) -> Option<u8> {
+        if requested.disambiguation.is_some_and(|disambiguation| self.disambiguation != Some(disambiguation)) {
+            return None;
+        }
+
+        let words: Vec<&str> = name_normalized.split_whitespace().collect();
+
+        match mode {
+            MatchModeFilterDto::Exact => requested.parts
+                .iter()
+                .all(|part| words.contains(&part.as_str()))
+                .then_some(100),
+            MatchModeFilterDto::Fuzzy => {
+                let similarity: f64 = requested.parts
+                    .iter()
+                    .map(|part| {
+                        words
+                            .iter()
+                            .map(|word| normalized_damerau_levenshtein(part, word))
+                            .fold(0.0, f64::max)
+                    })
+                    .sum::<f64>() / requested.parts.len() as f64;
+                let score: u8 = (similarity * 100.0).round() as u8;
+
+                (score >= MIN_FUZZY_MATCH_SCORE).then_some(score)
+            },
+        }
+    }
+
+    /// Form of a name used to compare names, both when importing and when searching: accents
+    /// are removed, letters are lowercased, hyphens separate words and apostrophes are dropped,
+    /// so "Léa Dupré-O'Neil" and "lea dupre oneil" are the same name
+    #[must_use]
+    pub fn normalize(name: &str) -> String {
+        let mut normalized: String = String::with_capacity(name.len());
+
+        for c in name.nfkd().filter(|c| !DIACRITICS.contains(c)).flat_map(char::to_lowercase) {
+            match c {
+                '-' | '‐' | '‑' | '‒' | '–' | '—' | '_' | '.' | ',' => normalized.push(' '),
+                '\'' | '’' | 'ʼ' | '‘' | '`' | '´' => (),
+                'ß' => normalized.push_str("ss"),
+                'æ' => normalized.push_str("ae"),
+                'œ' => normalized.push_str("oe"),
+                'ø' => normalized.push('o'),
+                'ł' => normalized.push('l'),
+                'đ' | 'ð' => normalized.push('d'),
+                'þ' => normalized.push_str("th"),
+                'ı' => normalized.push('i'),
+                c => normalized.push(c),
+            }
         }
+
+        normalized
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .nfc()
+            .collect()
     }
 }
 
@@ -19,3 +123,78 @@ impl From<UsernameDto> for String {
         value.name
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use crate::filters::MatchModeFilterDto;
+
+    use super::UsernameDto;
+
+    fn username(name: &str) -> UsernameDto {
+        let (parts, disambiguation): (Vec<String>, Option<u16>) = UsernameDto::split(name);
+
+        UsernameDto::new(name, parts, disambiguation)
+    }
+
+    fn match_score(name: &str, requested: &str, mode: MatchModeFilterDto) -> Option<u8> {
+        let name: UsernameDto = username(name);
+        let name_normalized: String = name.parts.join(" ");
+
+        name.match_score(&username(requested), &name_normalized, mode)
+    }
+
+    #[rstest]
+    #[case("Thomas Martin", "Thomas Martin", Some(100))]
+    #[case("Thomas Martin", "martin", Some(100))]
+    #[case("Thomas Martin", "Martin Thomas", Some(100))]
+    #[case("Léa Dupré-O'Neil", "lea dupre oneil", Some(100))]
+    #[case("Lea Dupre", "Léa Dupré", Some(100))]
+    #[case("Thomas Martin #2", "Thomas Martin", Some(100))]
+    #[case("Thomas Martin #2", "Thomas Martin #2", Some(100))]
+    #[case("Thomas Martin #2", "Thomas Martin #1", None)]
+    #[case("Thomas Martin", "Thomas Martin #1", None)]
+    #[case("Thomas Martin", "Tomas Martin", None)]
+    #[case("Thomas Martin", "Thomas Martinez", None)]
+    fn test_match_score_exact(
+        #[case] name: &str,
+        #[case] requested: &str,
+        #[case] expected: Option<u8>,
+    ) {
+        assert_eq!(expected, match_score(name, requested, MatchModeFilterDto::Exact));
+    }
+
+    #[rstest]
+    #[case("Thomas Martin", "Thomas Martin", Some(100))]
+    #[case("Thomas Martin", "Tomas Martin", Some(92))]
+    #[case("Thomas Martin", "Thomas Matrin", Some(92))]
+    #[case("Ivan Petrov", "Ivan Petrof", Some(92))]
+    #[case("Léa Dupré", "Lea Dupre", Some(100))]
+    #[case("Léa Dupré", "Lea Dupree", Some(92))]
+    #[case("Teen Lifter", "Open Lifter", Some(75))]
+    #[case("Thomas Martin #2", "Tomas Martin #1", None)]
+    #[case("Thomas Martin", "Powerlifter", None)]
+    fn test_match_score_fuzzy(
+        #[case] name: &str,
+        #[case] requested: &str,
+        #[case] expected: Option<u8>,
+    ) {
+        assert_eq!(expected, match_score(name, requested, MatchModeFilterDto::Fuzzy));
+    }
+
+    /// The score of a single word is its share of unchanged letters, 60% is the lowest match
+    #[rstest]
+    #[case("abcdefghij", "abcdefxxxx", Some(60))]
+    #[case("abcdefghij", "abcdexxxxx", None)]
+    #[case("abcde", "abcxx", Some(60))]
+    #[case("abcde", "abxxx", None)]
+    fn test_match_score_fuzzy_threshold(
+        #[case] name: &str,
+        #[case] requested: &str,
+        #[case] expected: Option<u8>,
+    ) {
+        assert_eq!(expected, match_score(name, requested, MatchModeFilterDto::Fuzzy));
+    }
+}
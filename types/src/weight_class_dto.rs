@@ -1,7 +1,5 @@
 use std::fmt::Display;
 
-use rust_decimal::Decimal;
-
 use crate::prelude::*;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -10,20 +8,38 @@ pub enum WeightClassDto {
     Over(WeightDto),
 }
 
-impl Display for WeightClassDto {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl WeightClassDto {
+    #[must_use]
+    pub const fn new(limit: WeightDto, over: bool) -> Self {
+        if over {
+            Self::Over(limit)
+        } else {
+            Self::UnderOrEqual(limit)
+        }
+    }
+
+    /// Weight bounding the class, the lifters of a "+" class weigh more than it
+    #[must_use]
+    pub const fn limit(self) -> WeightDto {
         match self {
             Self::UnderOrEqual(weight)
-                | Self::Over(weight) => f.write_str(&weight.to_string()),
+                | Self::Over(weight) => weight,
         }
     }
+
+    #[must_use]
+    pub const fn is_over(self) -> bool {
+        matches!(self, Self::Over(_))
+    }
 }
 
-impl From<WeightClassDto> for Decimal {
-    fn from(value: WeightClassDto) -> Self {
-        match value {
-            WeightClassDto::UnderOrEqual(weight)
-                | WeightClassDto::Over(weight) => weight.into(),
+/// Written like opl-data writes the classes, "105" or "120+", without the trailing zeros the
+/// database adds
+impl Display for WeightClassDto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnderOrEqual(weight) => write!(f, "{}", weight.0.normalize()),
+            Self::Over(weight) => write!(f, "{}+", weight.0.normalize()),
         }
     }
 }
@@ -0,0 +1,83 @@
+use rust_decimal::Decimal;
+use strum_macros::{Display, EnumIter, EnumString};
+
+use crate::prelude::*;
+
+/// Limits in tenths of kilogram of the classes, the last limit also opens the "+" class
+const IPF_MEN: [i64; 8] = [530, 590, 660, 740, 830, 930, 1050, 1200];
+const IPF_WOMEN: [i64; 8] = [430, 470, 520, 570, 630, 690, 760, 840];
+const IPF_2011_WOMEN: [i64; 7] = [430, 470, 520, 570, 630, 720, 840];
+const TRADITIONAL_MEN: [i64; 10] = [520, 560, 600, 675, 750, 825, 900, 1000, 1100, 1250];
+const TRADITIONAL_WOMEN: [i64; 9] = [440, 480, 520, 560, 600, 675, 750, 825, 900];
+
+/// Weight classes used by a federation over a period
+#[derive(Clone, Copy, Debug, Display, EnumIter, EnumString, Eq, PartialEq)]
+pub enum WeightClassSetDto {
+    /// Classes of the IPF since the women's 69 and 76 classes of 2021
+    #[strum(to_string = "IPF")]
+    Ipf,
+
+    /// Classes of the IPF from 2011 to 2020
+    #[strum(to_string = "IPF 2011-2020")]
+    Ipf2011,
+
+    /// Classes used by every federation before 2011, and by most of the federations outside
+    /// of the IPF since
+    #[strum(to_string = "Pre-2011")]
+    Traditional,
+}
+
+impl WeightClassSetDto {
+    /// Sets used by the meets of a federation, all of them for any federation. The federations
+    /// affiliated to the IPF use its sets too
+    #[must_use]
+    pub fn of_federation(federation: Option<&FederationDto>) -> Vec<Self> {
+        match federation {
+            Some(federation) if !federation.is_ipf_affiliated() => vec![Self::Traditional],
+            _ => vec![Self::Ipf, Self::Ipf2011, Self::Traditional],
+        }
+    }
+
+    const fn limits(self, sex: SexDto) -> &'static [i64] {
+        match (self, sex) {
+            (Self::Ipf | Self::Ipf2011, SexDto::M) => &IPF_MEN,
+            (Self::Ipf, SexDto::F) => &IPF_WOMEN,
+            (Self::Ipf2011, SexDto::F) => &IPF_2011_WOMEN,
+            (Self::Traditional, SexDto::M) => &TRADITIONAL_MEN,
+            (Self::Traditional, SexDto::F) => &TRADITIONAL_WOMEN,
+        }
+    }
+
+    fn weight(tenths: i64) -> WeightDto {
+        Decimal::new(tenths, 1).normalize().into()
+    }
+
+    /// Classes of the set, from the lightest to the "+" class
+    #[must_use]
+    pub fn classes(self, sex: SexDto) -> Vec<WeightClassDto> {
+        let limits: &[i64] = self.limits(sex);
+
+        limits
+            .iter()
+            .map(|&limit| WeightClassDto::UnderOrEqual(Self::weight(limit)))
+            .chain(limits.last().map(|&limit| WeightClassDto::Over(Self::weight(limit))))
+            .collect()
+    }
+
+    /// Bodyweights of the lifters falling into a class of the set, over the first bound and up
+    /// to the second one, `None` when the class isn't in the set
+    #[must_use]
+    pub fn bodyweights(self, sex: SexDto, class: WeightClassDto) -> Option<(WeightDto, Option<WeightDto>)> {
+        let limits: Vec<WeightDto> = self.limits(sex).iter().map(|&limit| Self::weight(limit)).collect();
+        let position: usize = limits.iter().position(|&limit| limit == class.limit())?;
+
+        match class {
+            WeightClassDto::Over(limit) => (position + 1 == limits.len()).then_some((limit, None)),
+            WeightClassDto::UnderOrEqual(limit) => {
+                let lower: WeightDto = position.checked_sub(1).map_or_else(|| Decimal::ZERO.into(), |previous| limits[previous]);
+
+                Some((lower, Some(limit)))
+            },
+        }
+    }
+}
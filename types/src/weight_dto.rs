@@ -1,17 +1,22 @@
 use std::fmt::Display;
 
-use rust_decimal::prelude::{FromPrimitive, Zero};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive, Zero};
 use rust_decimal::Decimal;
 
 const SCALE: u32 = 4;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct WeightDto(pub Decimal);
 
 impl WeightDto {
     fn zero() -> Self {
         Self::from(Decimal::zero())
     }
+
+    #[must_use]
+    pub fn is_zero(self) -> bool {
+        self.0.is_zero()
+    }
 }
 
 impl From<f32> for WeightDto {
@@ -35,6 +40,12 @@ impl From<WeightDto> for Decimal {
     }
 }
 
+impl From<WeightDto> for f64 {
+    fn from(value: WeightDto) -> Self {
+        value.0.to_f64().unwrap_or_default()
+    }
+}
+
 impl Display for WeightDto {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(&self.0.to_string())